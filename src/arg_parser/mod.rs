@@ -12,6 +12,7 @@ use error::*;
 
 enum CompilerParserFlag {
   Help,
+  MixedScriptLint,
 }
 
 #[derive(Clone, Copy)]
@@ -19,7 +20,7 @@ enum CompilerParserArgument {
   InputFile,
   OutputFile,
   CC,
-  LLC,
+  Llc,
 }
 
 trait CompilerParserProcess: Sized {
@@ -39,11 +40,13 @@ pub(crate) struct CompilerOptions {
   pub(crate) output_file: PathBuf,
   pub(crate) llc: PathBuf,
   pub(crate) cc: PathBuf,
+  pub(crate) mixed_script_lint: bool,
 }
 
 struct CompilerParser {
   state: CompilerParserState,
   help: bool,
+  mixed_script_lint: bool,
   input_file: Option<String>,
   output_file: Option<String>,
   llc: Option<String>,
@@ -54,6 +57,7 @@ impl CompilerParserProcess for CompilerParserFlag {
   fn from_argument(argument: &str) -> Option<Self> {
     match argument {
       "-h" | "--help" => Some(Self::Help),
+      "--lint-mixed-script" => Some(Self::MixedScriptLint),
       _ => None,
     }
   }
@@ -64,7 +68,7 @@ impl CompilerParserProcess for CompilerParserArgument {
     match argument {
       "-i" | "--input" => Some(Self::InputFile),
       "-o" | "--output" => Some(Self::OutputFile),
-      "--llc" => Some(Self::LLC),
+      "--llc" => Some(Self::Llc),
       "--cc" => Some(Self::CC),
       _ =>  None,
     }
@@ -77,15 +81,13 @@ impl CompilerParserArgument {
       CompilerParserArgument::InputFile => "--input",
       CompilerParserArgument::OutputFile => "--output",
       CompilerParserArgument::CC => "--cc",
-      CompilerParserArgument::LLC => "--llc",
+      CompilerParserArgument::Llc => "--llc",
     }
   }
 }
 
 fn default_option_resolve_path_strerror(path: Option<String>, default: &'static str) -> Result<PathBuf>  {
-  let path = path.as_ref()
-    .map(|path| path.as_str())
-    .unwrap_or(default);
+  let path = path.as_deref().unwrap_or(default);
 
   match which(path) {
     Ok(x) => Ok(x),
@@ -99,6 +101,7 @@ impl TryFrom<CompilerParser> for CompilerOptions {
   fn try_from(parser: CompilerParser) -> Result<Self> {
     let CompilerParser {
       help,
+      mixed_script_lint,
       input_file,
       output_file,
       llc,
@@ -129,6 +132,7 @@ impl TryFrom<CompilerParser> for CompilerOptions {
       output_file,
       llc,
       cc,
+      mixed_script_lint,
     })
   }
 }
@@ -138,6 +142,7 @@ impl CompilerParser {
     Self {
       state: CompilerParserState::First,
       help: false,
+      mixed_script_lint: false,
       input_file: None,
       output_file: None,
       llc: None,
@@ -150,7 +155,7 @@ impl CompilerParser {
       CompilerParserArgument::InputFile => &mut self.input_file,
       CompilerParserArgument::OutputFile => &mut self.output_file,
       CompilerParserArgument::CC => &mut self.cc,
-      CompilerParserArgument::LLC => &mut self.llc,
+      CompilerParserArgument::Llc => &mut self.llc,
     }
   }
 
@@ -178,6 +183,7 @@ impl CompilerParser {
     if let Some(flag) = CompilerParserFlag::from_argument(&argument) {
       match flag {
         CompilerParserFlag::Help => self.help = true,
+        CompilerParserFlag::MixedScriptLint => self.mixed_script_lint = true,
       };
 
       return ok;
@@ -0,0 +1,490 @@
+use crate::compiler::Symbol;
+use crate::tokenizer::{
+  NumericKind,
+  Operator,
+  Span,
+  StringKind,
+};
+
+/// The parsed contents of a single source file.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Ast {
+  pub(crate) declarations: Vec<Declaration>,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Ident {
+  pub(crate) name: Symbol,
+  pub(crate) span: Span,
+}
+
+/// A name that may be qualified by namespaces, like `io::stdin`, and given
+/// generic arguments, like `Something<i32>`.  `super` is kept as a segment
+/// named after the keyword.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Path {
+  /// Whether the path starts with a `::`, like `::new()`.
+  pub(crate) rooted: bool,
+  pub(crate) segments: Vec<Ident>,
+  /// The generic arguments of the last segment.
+  pub(crate) arguments: Vec<Type>,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Declaration {
+  pub(crate) kind: DeclarationKind,
+  /// The `template: T;` written before the declaration.
+  pub(crate) template: Option<Template>,
+  /// Whether the declaration starts with `export`.
+  pub(crate) exported: bool,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum DeclarationKind {
+  Function(Function),
+  TypeAlias(TypeAlias),
+  Struct(Struct),
+  Namespace(Namespace),
+  Interface(Interface),
+  Class(Class),
+  Impl(Impl),
+  Import(Import),
+  Test(Test),
+  /// A declaration that failed to parse, the error for which has already
+  /// been reported.
+  Error,
+}
+
+/// `import { a::b, c as d } from "path";` or `import name from "path";`
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Import {
+  pub(crate) kind: ImportKind,
+  /// The path to the imported file, as written.
+  pub(crate) source: String,
+  pub(crate) source_span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum ImportKind {
+  /// `import name from "path";`, which binds the whole module to `name`.
+  Module(Ident),
+  /// `import { a::b, c as d } from "path";`
+  Items(Vec<ImportItem>),
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct ImportItem {
+  pub(crate) path: Path,
+  pub(crate) alias: Option<Ident>,
+  pub(crate) span: Span,
+}
+
+/// `template: T, U extends A;`, which makes the declaration after it generic.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Template {
+  pub(crate) parameters: Vec<TemplateParameter>,
+  pub(crate) span: Span,
+}
+
+/// `T`, optionally followed by bounds like `extends A + B implements C`.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct TemplateParameter {
+  pub(crate) name: Ident,
+  pub(crate) bounds: Vec<Bound>,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Bound {
+  pub(crate) kind: BoundKind,
+  pub(crate) ty: Type,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BoundKind {
+  /// `extends`
+  Extends,
+  /// `implements`
+  Implements,
+  /// `satisfies`
+  Satisfies,
+}
+
+/// `interface Name extends A, B { members }`, where the members can be left
+/// out, as in `interface Name extends A, B;`.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Interface {
+  pub(crate) name: Ident,
+  pub(crate) extends: Vec<Type>,
+  pub(crate) members: Option<Vec<Member>>,
+}
+
+/// `class Name { members }`
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Class {
+  pub(crate) name: Ident,
+  pub(crate) members: Vec<Member>,
+}
+
+/// `impl Type { members }`, or `impl Type: Interface { members }`.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Impl {
+  pub(crate) target: Type,
+  pub(crate) interface: Option<Type>,
+  pub(crate) members: Vec<Member>,
+}
+
+/// A member of an interface, class or impl block, along with the modifiers
+/// that only members can have.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Member {
+  pub(crate) kind: MemberKind,
+  pub(crate) visibility: Option<Visibility>,
+  pub(crate) is_static: bool,
+  /// `mut`, for a method that changes the value it is called on.
+  pub(crate) is_mutable: bool,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum MemberKind {
+  Method(Box<Function>),
+  /// A member that failed to parse, the error for which has already been
+  /// reported.
+  Error,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Visibility {
+  /// `public`
+  Public,
+  /// `protected`
+  Protected,
+  /// `private`
+  Private,
+}
+
+/// `type Name := Type`
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct TypeAlias {
+  pub(crate) name: Ident,
+  pub(crate) ty: Type,
+}
+
+/// `struct Name { field: Type, ... }`
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Struct {
+  pub(crate) name: Ident,
+  pub(crate) fields: Vec<Field>,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Field {
+  pub(crate) name: Ident,
+  pub(crate) ty: Type,
+  pub(crate) span: Span,
+}
+
+/// `namespace name { declarations }`
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Namespace {
+  pub(crate) name: Ident,
+  pub(crate) declarations: Vec<Declaration>,
+}
+
+/// `test name { body }`, which is only compiled when running tests.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Test {
+  pub(crate) name: Ident,
+  pub(crate) body: Block,
+}
+
+/// `name -> Ret: arg: T, arg2: U { body }`, where both the return type and the
+/// parameters are optional.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Function {
+  pub(crate) name: Ident,
+  pub(crate) is_extern: bool,
+  pub(crate) is_abstract: bool,
+  pub(crate) return_type: Option<Type>,
+  /// The `this` that comes before the other parameters of a method.
+  pub(crate) receiver: Option<Receiver>,
+  pub(crate) parameters: Vec<Parameter>,
+  /// Whether the parameters end with a C-style `...`, which only `extern`
+  /// functions may do.
+  pub(crate) is_variadic: bool,
+  /// Only `extern` and `abstract` functions go without a body.
+  pub(crate) body: Option<FunctionBody>,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Receiver {
+  pub(crate) kind: ReceiverKind,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReceiverKind {
+  /// `this`
+  Value,
+  /// `mut this`
+  MutableValue,
+  /// `&this`
+  Reference,
+  /// `&mut this`
+  MutableReference,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Parameter {
+  pub(crate) name: Ident,
+  pub(crate) ty: Type,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum FunctionBody {
+  Block(Block),
+  /// The raw IR of an `__llvm { ... }` body.
+  InlineLlvm(String, Span),
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Type {
+  pub(crate) kind: TypeKind,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum TypeKind {
+  Void,
+  /// `This`, the type that is being implemented.
+  This,
+  Path(Path),
+  /// `&T`, or `&mut T` if `mutable` is set.
+  Reference {
+    mutable: bool,
+    inner: Box<Type>,
+  },
+  /// `[]T`
+  Slice(Box<Type>),
+  /// `[size]T`
+  Array {
+    size: Box<Expression>,
+    element: Box<Type>,
+  },
+}
+
+/// `{ a; b; c }`, which evaluates to its tail expression `c`, or to nothing
+/// if every expression in it ends with a `;`.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Block {
+  pub(crate) statements: Vec<Statement>,
+  pub(crate) tail: Option<Box<Expression>>,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Statement {
+  pub(crate) kind: StatementKind,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum StatementKind {
+  Expression(Expression),
+  Local(Local),
+  /// A statement that failed to parse, the error for which has already been
+  /// reported.
+  Error,
+}
+
+/// `name := value`, `name: Type := value` or `name: Type`, any of which may
+/// start with `mut`.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Local {
+  pub(crate) mutable: bool,
+  pub(crate) name: Ident,
+  pub(crate) ty: Option<Type>,
+  pub(crate) value: Option<Expression>,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Expression {
+  pub(crate) kind: ExpressionKind,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum ExpressionKind {
+  Literal(Literal),
+  Path(Path),
+  Block(Block),
+  /// The `$` in `x |> f($)`.
+  Placeholder,
+  /// `this`, inside a method.
+  This,
+  Unary {
+    operator: UnaryOperator,
+    operand: Box<Expression>,
+  },
+  Postfix {
+    operator: PostfixOperator,
+    operand: Box<Expression>,
+  },
+  /// Any infix operator, including assignments.
+  Binary {
+    operator: Operator,
+    left: Box<Expression>,
+    right: Box<Expression>,
+  },
+  /// `object.member`, or `object->member` if `arrow` is set.
+  Member {
+    object: Box<Expression>,
+    member: Ident,
+    arrow: bool,
+  },
+  Call {
+    callee: Box<Expression>,
+    arguments: Vec<Expression>,
+  },
+  Index {
+    object: Box<Expression>,
+    index: Box<Expression>,
+  },
+  /// `expression: Type`
+  Cast {
+    expression: Box<Expression>,
+    ty: Type,
+  },
+  /// `if condition { } else ...`, or `unless` if `negated` is set.  The
+  /// `else` is either a block or another `if` or `unless`.
+  If {
+    negated: bool,
+    condition: Box<Expression>,
+    then: Block,
+    otherwise: Option<Box<Expression>>,
+  },
+  /// `while condition { }`, or `until` if `negated` is set.  Written as
+  /// `do { } while condition`, the body runs once before the first check.
+  While {
+    negated: bool,
+    body_first: bool,
+    condition: Box<Expression>,
+    body: Block,
+  },
+  Loop(Block),
+  /// `for item in items { }`
+  For {
+    binding: Ident,
+    iterable: Box<Expression>,
+    body: Block,
+  },
+  Switch {
+    value: Box<Expression>,
+    arms: Vec<Arm>,
+  },
+  Match {
+    value: Box<Expression>,
+    arms: Vec<Arm>,
+  },
+  Break(Option<Box<Expression>>),
+  Continue,
+  Return(Option<Box<Expression>>),
+  /// `Name { field: value, ... }`
+  StructLiteral {
+    path: Path,
+    fields: Vec<FieldValue>,
+  },
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct FieldValue {
+  pub(crate) name: Ident,
+  pub(crate) value: Expression,
+  pub(crate) span: Span,
+}
+
+/// `pattern => value` in a `switch` or `match`.
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) struct Arm {
+  pub(crate) pattern: Expression,
+  pub(crate) value: Expression,
+  pub(crate) span: Span,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnaryOperator {
+  /// `~`
+  BitNot,
+  /// `!`
+  LogicalNot,
+  /// `-`
+  Negate,
+  /// `&`
+  Reference,
+  /// `&mut`
+  MutableReference,
+}
+
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PostfixOperator {
+  /// `++`
+  Increment,
+  /// `--`
+  Decrement,
+  /// `?`
+  Try,
+}
+
+#[allow(unused)]
+#[derive(Debug)]
+pub(crate) enum Literal {
+  Numeric(NumericKind),
+  String(StringKind),
+  Char(char),
+  ByteChar(u8),
+  Bool(bool),
+}
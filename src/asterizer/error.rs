@@ -0,0 +1,31 @@
+use snafu::prelude::*;
+
+use crate::tokenizer::Span;
+use crate::tokenizer::error::What;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub(crate) enum AsterError {
+  #[snafu(display("expected {}, found {}", what.as_definite(), found.as_definite()))]
+  Expected { what: What, found: What, span: Span },
+
+  #[snafu(display("expected `;` between statements, found {}", found.as_definite()))]
+  MissingSemicolon { found: What, span: Span },
+
+  #[snafu(display("expected `}};` at the end of the declaration, found {}", found.as_definite()))]
+  UnterminatedDeclaration { found: What, span: Span },
+
+  #[snafu(display("expected a declaration after the template, found {}", found.as_definite()))]
+  DanglingTemplate { found: What, span: Span },
+}
+
+impl AsterError {
+  pub(crate) fn span(&self) -> Span {
+    match self {
+      | AsterError::Expected { span, .. }
+      | AsterError::MissingSemicolon { span, .. }
+      | AsterError::UnterminatedDeclaration { span, .. }
+      | AsterError::DanglingTemplate { span, .. } => *span,
+    }
+  }
+}
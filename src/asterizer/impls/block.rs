@@ -0,0 +1,160 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Block,
+  Expression,
+  ExpressionKind,
+  Ident,
+  Local,
+  Statement,
+  StatementKind,
+  error::*,
+};
+use crate::asterizer::impls::recovery::Resync;
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Punctuation,
+  Span,
+  error::What,
+};
+
+/// What a block reads next: either a whole statement, or the expression that
+/// is the value of the block.
+enum Item {
+  Statement(Statement),
+  Tail(Expression),
+}
+
+impl Asterizer {
+  /// Reads `{ a; b; c }`.  An expression that isn't followed by a `;` has to
+  /// be the last one, and becomes the value of the block.
+  pub(in crate::asterizer) fn block(&mut self) -> Result<Block> {
+    trace!("Asterizer::block");
+
+    let start = self.expect(Grouping::OpenBrace)?;
+
+    let mut statements = vec![];
+    let mut tail = None;
+
+    let end = loop {
+      if self.peek().is_none() || self.is(Grouping::CloseBrace) {
+        break self.expect(Grouping::CloseBrace)?;
+      };
+
+      let start = self.position;
+      let start_span = self.peek_span();
+
+      match self.with_struct_literals(true, Self::statement) {
+        Ok(Item::Statement(statement)) => statements.push(statement),
+        Ok(Item::Tail(expression)) if self.peek().is_none() || self.is(Grouping::CloseBrace) => {
+          tail = Some(Box::new(expression));
+        },
+        Ok(Item::Tail(expression)) => {
+          // carry on as if the `;` was there
+          self.report(MissingSemicolonSnafu { found: self.found(), span: expression.span.after() }.build());
+
+          statements.push(Statement {
+            span: expression.span,
+            kind: StatementKind::Expression(expression),
+          });
+        },
+        Err(err) => statements.push(Statement {
+          span: self.recover(err, start, start_span, Resync::InBraces),
+          kind: StatementKind::Error,
+        }),
+      };
+    };
+
+    Ok(Block {
+      statements,
+      tail,
+      span: start.to(end),
+    })
+  }
+
+  fn statement(&mut self) -> Result<Item> {
+    trace!("Asterizer::statement");
+
+    let start = self.peek_span();
+    let mutable = self.eat(Keyword::Mut).is_some();
+
+    let expression = self.expression()?;
+
+    if self.eat(Punctuation::Bollocks).is_some() {
+      let value = self.expression()?;
+      let local = Self::local(mutable, expression, Some(value))?;
+
+      return self.end_statement(start, StatementKind::Local(local));
+    };
+
+    // `name: Type;` declares a variable without giving it a value, but
+    // `name: Type` on its own is a cast that may be the value of the block
+    let declares = mutable || (
+      matches!(&expression.kind, ExpressionKind::Cast { expression, .. } if Self::local_name(expression).is_some())
+        && self.is(Punctuation::Semicolon)
+    );
+
+    if declares {
+      let local = Self::local(mutable, expression, None)?;
+
+      return self.end_statement(start, StatementKind::Local(local));
+    };
+
+    let Some(semicolon) = self.eat(Punctuation::Semicolon) else {
+      return Ok(Item::Tail(expression));
+    };
+
+    Ok(Item::Statement(Statement {
+      span: start.to(semicolon),
+      kind: StatementKind::Expression(expression),
+    }))
+  }
+
+  fn end_statement(&mut self, start: Span, kind: StatementKind) -> Result<Item> {
+    let Some(semicolon) = self.eat(Punctuation::Semicolon) else {
+      let end = self.previous_span();
+      self.report(MissingSemicolonSnafu { found: self.found(), span: end.after() }.build());
+
+      return Ok(Item::Statement(Statement {
+        span: start.to(end),
+        kind,
+      }));
+    };
+
+    Ok(Item::Statement(Statement {
+      span: start.to(semicolon),
+      kind,
+    }))
+  }
+
+  /// Builds a declaration out of what was read as `name` or `name: Type`.
+  fn local(mutable: bool, target: Expression, value: Option<Expression>) -> Result<Local> {
+    let (name, ty) = match target.kind {
+      ExpressionKind::Cast { expression, ty } => (Self::local_name(&expression), Some(ty)),
+      ref kind => (Self::local_name_of(kind), None),
+    };
+
+    let Some(name) = name else {
+      return ExpectedSnafu { what: What::Identifier, found: What::Expression, span: target.span }.fail()?;
+    };
+
+    Ok(Local {
+      mutable,
+      name,
+      ty,
+      value,
+    })
+  }
+
+  fn local_name(expression: &Expression) -> Option<Ident> {
+    Self::local_name_of(&expression.kind)
+  }
+
+  fn local_name_of(kind: &ExpressionKind) -> Option<Ident> {
+    match kind {
+      ExpressionKind::Path(path) if path.segments.len() == 1 && path.arguments.is_empty() => Some(path.segments[0]),
+      _ => None,
+    }
+  }
+}
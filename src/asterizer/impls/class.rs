@@ -0,0 +1,141 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Class,
+  Impl,
+  Interface,
+  Member,
+  MemberKind,
+  Visibility,
+};
+use crate::asterizer::impls::recovery::Resync;
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Punctuation,
+  TokenKind,
+};
+
+impl Asterizer {
+  /// Reads `interface Name extends A, B { members }`, or the same without
+  /// the members, up to but not including the `;` after it.
+  pub(in crate::asterizer) fn interface(&mut self) -> Result<Interface> {
+    trace!("Asterizer::interface");
+
+    self.expect(Keyword::Interface)?;
+    let name = self.identifier()?;
+
+    let mut extends = vec![];
+
+    if self.eat(Keyword::Extends).is_some() {
+      loop {
+        extends.push(self.ty()?);
+
+        if self.eat(Punctuation::Comma).is_none() {
+          break;
+        };
+      };
+    };
+
+    let members = if self.is(Punctuation::Semicolon) {
+      None
+    } else {
+      Some(self.members(true)?)
+    };
+
+    Ok(Interface { name, extends, members })
+  }
+
+  /// Reads `class Name { members }`, up to but not including the `;` after
+  /// it.
+  pub(in crate::asterizer) fn class(&mut self) -> Result<Class> {
+    trace!("Asterizer::class");
+
+    self.expect(Keyword::Class)?;
+    let name = self.identifier()?;
+    let members = self.members(false)?;
+
+    Ok(Class { name, members })
+  }
+
+  /// Reads `impl Type: Interface { members }`, where the interface is
+  /// optional, up to but not including the `;` after it.
+  pub(in crate::asterizer) fn impl_block(&mut self) -> Result<Impl> {
+    trace!("Asterizer::impl_block");
+
+    self.expect(Keyword::Impl)?;
+    let target = self.ty()?;
+
+    let interface = match self.eat(Punctuation::Colon) {
+      Some(_) => Some(self.ty()?),
+      None => None,
+    };
+
+    let members = self.members(false)?;
+
+    Ok(Impl { target, interface, members })
+  }
+
+  /// Reads `{ member; member; }`.  Members of an interface can leave out
+  /// their bodies.
+  fn members(&mut self, bodies_optional: bool) -> Result<Vec<Member>> {
+    self.expect(Grouping::OpenBrace)?;
+
+    let mut members = vec![];
+
+    while self.peek().is_some() && !self.is(Grouping::CloseBrace) {
+      members.push(self.member_or_error(bodies_optional));
+    };
+
+    self.expect(Grouping::CloseBrace)?;
+
+    Ok(members)
+  }
+
+  /// Reads a member, or reports what is wrong with it and leaves an error in
+  /// its place.
+  fn member_or_error(&mut self, body_optional: bool) -> Member {
+    let start = self.position;
+    let start_span = self.peek_span();
+
+    self.member(body_optional).unwrap_or_else(|err| Member {
+      span: self.recover(err, start, start_span, Resync::InBraces),
+      kind: MemberKind::Error,
+      visibility: None,
+      is_static: false,
+      is_mutable: false,
+    })
+  }
+
+  /// Reads a method with its modifiers, including the `;` after it.
+  fn member(&mut self, body_optional: bool) -> Result<Member> {
+    trace!("Asterizer::member");
+
+    let start = self.peek_span();
+
+    let visibility = match self.peek_kind() {
+      Some(TokenKind::Keyword(Keyword::Public)) => Some(Visibility::Public),
+      Some(TokenKind::Keyword(Keyword::Protected)) => Some(Visibility::Protected),
+      Some(TokenKind::Keyword(Keyword::Private)) => Some(Visibility::Private),
+      _ => None,
+    };
+
+    if visibility.is_some() {
+      self.seek();
+    };
+
+    let is_static = self.eat(Keyword::Static).is_some();
+    let is_mutable = self.eat(Keyword::Mut).is_some();
+    let function = self.function(body_optional)?;
+
+    let end = self.terminator(function.body.is_some())?;
+
+    Ok(Member {
+      kind: MemberKind::Method(Box::new(function)),
+      visibility,
+      is_static,
+      is_mutable,
+      span: start.to(end),
+    })
+  }
+}
@@ -0,0 +1,158 @@
+use crate::Result;
+use crate::asterizer::{
+  Arm,
+  Asterizer,
+  Expression,
+  ExpressionKind,
+  error::*,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Punctuation,
+  Span,
+  TokenKind,
+  error::What,
+};
+
+impl Asterizer {
+  /// Reads an expression that starts with a control-flow keyword.
+  pub(in crate::asterizer) fn control_flow(&mut self) -> Result<Expression> {
+    trace!("Asterizer::control_flow");
+
+    let start = self.peek_span();
+
+    let Some(&TokenKind::Keyword(keyword)) = self.peek_kind() else {
+      return self.expected(What::Expression);
+    };
+
+    self.seek();
+
+    let kind = match keyword {
+      Keyword::If | Keyword::Unless => return self.conditional(start, keyword == Keyword::Unless),
+      Keyword::While | Keyword::Until => ExpressionKind::While {
+        negated: keyword == Keyword::Until,
+        body_first: false,
+        condition: Box::new(self.condition()?),
+        body: self.block()?,
+      },
+      Keyword::Do => {
+        let body = self.block()?;
+
+        let negated = if self.eat(Keyword::Until).is_some() {
+          true
+        } else {
+          self.expect(Keyword::While)?;
+          false
+        };
+
+        ExpressionKind::While {
+          negated,
+          body_first: true,
+          condition: Box::new(self.expression()?),
+          body,
+        }
+      },
+      Keyword::Loop => ExpressionKind::Loop(self.block()?),
+      Keyword::For => {
+        let binding = self.identifier()?;
+        self.expect(Keyword::In)?;
+
+        ExpressionKind::For {
+          binding,
+          iterable: Box::new(self.condition()?),
+          body: self.block()?,
+        }
+      },
+      Keyword::Switch => ExpressionKind::Switch {
+        value: Box::new(self.condition()?),
+        arms: self.with_struct_literals(true, Self::arms)?,
+      },
+      Keyword::Match => ExpressionKind::Match {
+        value: Box::new(self.condition()?),
+        arms: self.with_struct_literals(true, Self::arms)?,
+      },
+      Keyword::Break => ExpressionKind::Break(self.jump_value()?),
+      Keyword::Continue => ExpressionKind::Continue,
+      Keyword::Return => ExpressionKind::Return(self.jump_value()?),
+      _ => return ExpectedSnafu { what: What::Expression, found: What::Exactly(keyword.to_str()), span: start }.fail()?,
+    };
+
+    Ok(Expression {
+      kind,
+      span: start.to(self.previous_span()),
+    })
+  }
+
+  /// Reads the rest of an `if` or `unless`, whose keyword has already been
+  /// consumed, along with any `else`.
+  fn conditional(&mut self, start: Span, negated: bool) -> Result<Expression> {
+    let condition = self.condition()?;
+    let then = self.block()?;
+
+    let otherwise = if self.eat(Keyword::Else).is_none() {
+      None
+    } else if self.is(Keyword::If) || self.is(Keyword::Unless) {
+      Some(Box::new(self.control_flow()?))
+    } else {
+      let block = self.block()?;
+
+      Some(Box::new(Expression { span: block.span, kind: ExpressionKind::Block(block) }))
+    };
+
+    Ok(Expression {
+      kind: ExpressionKind::If {
+        negated,
+        condition: Box::new(condition),
+        then,
+        otherwise,
+      },
+      span: start.to(self.previous_span()),
+    })
+  }
+
+  /// Reads an expression that is followed by a block, which can't be read as
+  /// a struct literal.
+  fn condition(&mut self) -> Result<Expression> {
+    self.with_struct_literals(false, Self::expression)
+  }
+
+  /// Reads `{ pattern => value, ... }`.  The `,` may be left out after a
+  /// value that is a block.
+  fn arms(&mut self) -> Result<Vec<Arm>> {
+    self.expect(Grouping::OpenBrace)?;
+
+    let mut arms = vec![];
+
+    while !self.is(Grouping::CloseBrace) {
+      let pattern = self.expression()?;
+      self.expect(Punctuation::FatArrow)?;
+      let value = self.expression()?;
+
+      let is_block = matches!(value.kind, ExpressionKind::Block(_));
+
+      arms.push(Arm {
+        span: pattern.span.to(value.span),
+        pattern,
+        value,
+      });
+
+      if self.eat(Punctuation::Comma).is_none() && !is_block {
+        break;
+      };
+    };
+
+    self.expect(Grouping::CloseBrace)?;
+
+    Ok(arms)
+  }
+
+  /// Reads the value of a `break` or `return`, if there is one.
+  fn jump_value(&mut self) -> Result<Option<Box<Expression>>> {
+    if !self.starts_operand() {
+      return Ok(None);
+    };
+
+    Ok(Some(Box::new(self.expression()?)))
+  }
+}
@@ -0,0 +1,123 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Declaration,
+  DeclarationKind,
+  error::*,
+};
+use crate::asterizer::impls::recovery::Resync;
+use crate::tokenizer::{
+  Keyword,
+  Punctuation,
+  Span,
+  TokenKind,
+  error::What,
+};
+
+impl DeclarationKind {
+  /// Whether the declaration ends with a `}`, after which a `;` is easy to
+  /// forget.
+  fn ends_with_brace(&self) -> bool {
+    match self {
+      DeclarationKind::Function(function) => function.body.is_some(),
+      DeclarationKind::Interface(interface) => interface.members.is_some(),
+      | DeclarationKind::TypeAlias(_)
+      | DeclarationKind::Import(_)
+      | DeclarationKind::Error => false,
+      | DeclarationKind::Struct(_)
+      | DeclarationKind::Namespace(_)
+      | DeclarationKind::Class(_)
+      | DeclarationKind::Impl(_)
+      | DeclarationKind::Test(_) => true,
+    }
+  }
+}
+
+impl Asterizer {
+  /// Reads a declaration, or reports what is wrong with it and leaves an
+  /// error in its place.
+  pub(in crate::asterizer) fn declaration_or_error(&mut self, resync: Resync) -> Declaration {
+    let start = self.position;
+    let start_span = self.peek_span();
+
+    self.declaration().unwrap_or_else(|err| Declaration {
+      span: self.recover(err, start, start_span, resync),
+      kind: DeclarationKind::Error,
+      template: None,
+      exported: false,
+    })
+  }
+
+  /// Reads a declaration, including the `;` that ends it.
+  pub(in crate::asterizer) fn declaration(&mut self) -> Result<Declaration> {
+    trace!("Asterizer::declaration");
+
+    let start = self.peek_span();
+
+    let template = if self.starts_template() {
+      Some(self.template()?)
+    } else {
+      None
+    };
+
+    // another `template` would otherwise be read as a function named
+    // `template`
+    if let Some(template) = &template {
+      if self.starts_template() {
+        return DanglingTemplateSnafu { found: self.found(), span: template.span }.fail()?;
+      };
+    };
+
+    let exported = self.eat(Keyword::Export).is_some();
+
+    let kind = match self.peek_kind() {
+      Some(TokenKind::Keyword(Keyword::Extern | Keyword::Abstract)) => DeclarationKind::Function(self.function(false)?),
+      Some(TokenKind::Keyword(Keyword::Type)) => DeclarationKind::TypeAlias(self.type_alias()?),
+      Some(TokenKind::Keyword(Keyword::Struct)) => DeclarationKind::Struct(self.structure()?),
+      Some(TokenKind::Keyword(Keyword::Namespace)) => DeclarationKind::Namespace(self.namespace()?),
+      Some(TokenKind::Keyword(Keyword::Interface)) => DeclarationKind::Interface(self.interface()?),
+      Some(TokenKind::Keyword(Keyword::Class)) => DeclarationKind::Class(self.class()?),
+      Some(TokenKind::Keyword(Keyword::Impl)) => DeclarationKind::Impl(self.impl_block()?),
+      Some(TokenKind::Keyword(Keyword::Import)) => DeclarationKind::Import(self.import()?),
+      Some(TokenKind::Keyword(Keyword::Test)) if self.starts_test() => DeclarationKind::Test(self.test()?),
+      Some(_) if self.is_identifier() => DeclarationKind::Function(self.function(false)?),
+      _ => return match &template {
+        Some(template) => DanglingTemplateSnafu { found: self.found(), span: template.span }.fail()?,
+        None => self.expected(What::Declaration),
+      },
+    };
+
+    // only methods have a `this` to take
+    if let DeclarationKind::Function(function) = &kind {
+      if let Some(receiver) = &function.receiver {
+        return ExpectedSnafu { what: What::Parameter, found: What::Receiver, span: receiver.span }.fail()?;
+      };
+    };
+
+    let end = self.terminator(kind.ends_with_brace())?;
+
+    Ok(Declaration {
+      kind,
+      template,
+      exported,
+      span: start.to(end),
+    })
+  }
+
+  /// Reads the `;` after a declaration or member.
+  pub(in crate::asterizer) fn terminator(&mut self, ends_with_brace: bool) -> Result<Span> {
+    // a body on its own looks finished, so point out the missing `;` after
+    // its `}` rather than at whatever comes next, and carry on as if it was
+    // there
+    match self.eat(Punctuation::Semicolon) {
+      Some(end) => Ok(end),
+      None if ends_with_brace => {
+        let end = self.previous_span();
+        self.report(UnterminatedDeclarationSnafu { found: self.found(), span: end.after() }.build());
+
+        Ok(end)
+      },
+      None => self.expect(Punctuation::Semicolon),
+    }
+  }
+}
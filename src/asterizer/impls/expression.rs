@@ -0,0 +1,337 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Expression,
+  ExpressionKind,
+  Literal,
+  PostfixOperator,
+  Type,
+  UnaryOperator,
+};
+use crate::asterizer::impls::precedence::{
+  Associativity,
+  Precedence,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Operator,
+  Punctuation,
+  Span,
+  TokenKind,
+  error::What,
+};
+
+impl Asterizer {
+  pub(in crate::asterizer) fn expression(&mut self) -> Result<Expression> {
+    trace!("Asterizer::expression");
+
+    self.binary(Precedence::Assignment as u8)
+  }
+
+  /// Reads operands joined by infix operators that bind at least as tightly
+  /// as `min_precedence`, along with casts.
+  fn binary(&mut self, min_precedence: u8) -> Result<Expression> {
+    let mut left = self.unary()?;
+
+    loop {
+      let (precedence, associativity, operator) = match self.peek_kind() {
+        Some(&TokenKind::Operator(operator)) => match operator.infix() {
+          Some((precedence, associativity)) => (precedence, associativity, Some(operator)),
+          None => break,
+        },
+        Some(TokenKind::Punctuation(Punctuation::Colon)) => (Precedence::Cast, Associativity::Left, None),
+        _ => break,
+      };
+
+      if (precedence as u8) < min_precedence {
+        break;
+      };
+
+      self.seek();
+
+      let Some(operator) = operator else {
+        let ty = self.ty()?;
+
+        left = Expression {
+          span: left.span.to(ty.span),
+          kind: ExpressionKind::Cast {
+            expression: Box::new(left),
+            ty,
+          },
+        };
+
+        continue;
+      };
+
+      let right = self.binary(precedence.right_side(associativity))?;
+
+      left = Expression {
+        span: left.span.to(right.span),
+        kind: ExpressionKind::Binary {
+          operator,
+          left: Box::new(left),
+          right: Box::new(right),
+        },
+      };
+    };
+
+    Ok(left)
+  }
+
+  fn unary(&mut self) -> Result<Expression> {
+    let start = self.peek_span();
+
+    let operator = match self.peek_kind() {
+      Some(TokenKind::Operator(Operator::BitNot)) => UnaryOperator::BitNot,
+      Some(TokenKind::Operator(Operator::LogicalNot)) => UnaryOperator::LogicalNot,
+      Some(TokenKind::Operator(Operator::Minus)) => UnaryOperator::Negate,
+      Some(TokenKind::Operator(Operator::Ampersand | Operator::LogicalAnd)) => UnaryOperator::Reference,
+      _ => return self.postfix(),
+    };
+
+    // `&&x` is a reference to a reference
+    let double = self.is(Operator::LogicalAnd);
+    self.seek();
+
+    let operator = match (operator, self.eat(Keyword::Mut)) {
+      (UnaryOperator::Reference, Some(_)) => UnaryOperator::MutableReference,
+      _ => operator,
+    };
+
+    let operand = self.binary(Precedence::Prefix as u8)?;
+
+    let inner_start = if double {
+      Span { start: start.start + 1, ..start }
+    } else {
+      start
+    };
+
+    let expression = Expression {
+      span: inner_start.to(operand.span),
+      kind: ExpressionKind::Unary {
+        operator,
+        operand: Box::new(operand),
+      },
+    };
+
+    if !double {
+      return Ok(expression);
+    };
+
+    Ok(Expression {
+      span: start.to(expression.span),
+      kind: ExpressionKind::Unary {
+        operator: UnaryOperator::Reference,
+        operand: Box::new(expression),
+      },
+    })
+  }
+
+  /// Reads an operand followed by any number of calls, indexes, member
+  /// accesses and postfix operators.
+  fn postfix(&mut self) -> Result<Expression> {
+    let mut expression = self.primary()?;
+
+    loop {
+      let start = expression.span;
+
+      let kind = match self.peek_kind() {
+        Some(TokenKind::Grouping(Grouping::OpenParenthesis)) => {
+          self.seek();
+
+          ExpressionKind::Call {
+            callee: Box::new(expression),
+            arguments: self.arguments()?,
+          }
+        },
+        Some(TokenKind::Grouping(Grouping::OpenBracket)) => {
+          self.seek();
+
+          let index = self.with_struct_literals(true, Self::expression)?;
+          self.expect(Grouping::CloseBracket)?;
+
+          ExpressionKind::Index {
+            object: Box::new(expression),
+            index: Box::new(index),
+          }
+        },
+        Some(kind @ (TokenKind::Operator(Operator::Dot) | TokenKind::Punctuation(Punctuation::RightArrow))) => {
+          let arrow = matches!(kind, TokenKind::Punctuation(_));
+          self.seek();
+
+          ExpressionKind::Member {
+            object: Box::new(expression),
+            member: self.identifier()?,
+            arrow,
+          }
+        },
+        Some(TokenKind::Operator(operator @ (Operator::Increment | Operator::Decrement | Operator::Try))) => {
+          let operator = match operator {
+            Operator::Increment => PostfixOperator::Increment,
+            Operator::Decrement => PostfixOperator::Decrement,
+            _ => PostfixOperator::Try,
+          };
+
+          self.seek();
+
+          ExpressionKind::Postfix {
+            operator,
+            operand: Box::new(expression),
+          }
+        },
+        _ => break,
+      };
+
+      expression = Expression {
+        span: start.to(self.previous_span()),
+        kind,
+      };
+    };
+
+    Ok(expression)
+  }
+
+  /// Reads the arguments of a call, whose `(` has already been consumed, up
+  /// to and including the `)`.
+  fn arguments(&mut self) -> Result<Vec<Expression>> {
+    let mut arguments = vec![];
+
+    while !self.is(Grouping::CloseParenthesis) {
+      arguments.push(self.with_struct_literals(true, Self::expression)?);
+
+      if self.eat(Punctuation::Comma).is_none() {
+        break;
+      };
+    };
+
+    self.expect(Grouping::CloseParenthesis)?;
+
+    Ok(arguments)
+  }
+
+  fn primary(&mut self) -> Result<Expression> {
+    let span = self.peek_span();
+
+    let literal = match self.peek_kind() {
+      Some(TokenKind::Numeric(numeric)) => Some(Literal::Numeric(numeric.clone())),
+      Some(TokenKind::String(string)) => Some(Literal::String(string.clone())),
+      Some(TokenKind::Char(ch)) => Some(Literal::Char(*ch)),
+      Some(TokenKind::ByteChar(byte)) => Some(Literal::ByteChar(*byte)),
+      Some(TokenKind::Keyword(Keyword::True)) => Some(Literal::Bool(true)),
+      Some(TokenKind::Keyword(Keyword::False)) => Some(Literal::Bool(false)),
+      _ => None,
+    };
+
+    if let Some(literal) = literal {
+      self.seek();
+
+      return Ok(Expression { kind: ExpressionKind::Literal(literal), span });
+    };
+
+    if let Some(TokenKind::PipePlaceholder) = self.peek_kind() {
+      self.seek();
+
+      return Ok(Expression { kind: ExpressionKind::Placeholder, span });
+    };
+
+    if self.eat(Grouping::OpenParenthesis).is_some() {
+      let expression = self.with_struct_literals(true, Self::expression)?;
+      let end = self.expect(Grouping::CloseParenthesis)?;
+
+      return Ok(Expression { span: span.to(end), ..expression });
+    };
+
+    if let Some(TokenKind::Keyword(keyword)) = self.peek_kind() {
+      if keyword.is_control_flow() {
+        return self.control_flow();
+      };
+    };
+
+    if self.is(Grouping::OpenBrace) {
+      let block = self.block()?;
+
+      return Ok(Expression { span: block.span, kind: ExpressionKind::Block(block) });
+    };
+
+    if let Some(span) = self.eat(Keyword::This) {
+      return Ok(Expression { kind: ExpressionKind::This, span });
+    };
+
+    if self.is_identifier() || self.is(Keyword::Super) || self.is(Punctuation::DoubleColon) {
+      let mut path = self.path()?;
+
+      if let Some(arguments) = self.try_generic_arguments() {
+        path.arguments = arguments;
+        path.span = path.span.to(self.previous_span());
+      };
+
+      if self.struct_literals && self.is(Grouping::OpenBrace) {
+        return self.struct_literal(path);
+      };
+
+      return Ok(Expression { span: path.span, kind: ExpressionKind::Path(path) });
+    };
+
+    self.expected(What::Expression)
+  }
+
+  /// Tries to read generic arguments after a path in an expression, where
+  /// `a < b > (c)` could also be two comparisons.  Like in C#, it is read as
+  /// generic arguments whenever what follows the `>` can't continue a
+  /// comparison, or is a `(` or `{`, and as comparisons otherwise.
+  fn try_generic_arguments(&mut self) -> Option<Vec<Type>> {
+    if !self.is(Operator::LessThan) {
+      return None;
+    };
+
+    let snapshot = self.snapshot();
+    self.seek();
+
+    if let Ok(arguments) = self.generic_arguments() {
+      if self.follows_generic_arguments() {
+        return Some(arguments);
+      };
+    };
+
+    self.restore(snapshot);
+
+    None
+  }
+
+  fn follows_generic_arguments(&self) -> bool {
+    match self.peek_kind() {
+      Some(TokenKind::Grouping(Grouping::OpenParenthesis | Grouping::OpenBrace)) => true,
+      Some(TokenKind::Operator(operator)) => *operator == Operator::Dot,
+      _ => !self.starts_operand(),
+    }
+  }
+
+  /// Whether the next token can be the start of an operand, which must only
+  /// be true for tokens that `unary` and `primary` can read.
+  pub(in crate::asterizer) fn starts_operand(&self) -> bool {
+    if let Some(TokenKind::Keyword(keyword)) = self.peek_kind() {
+      if keyword.is_control_flow() {
+        return true;
+      };
+    };
+
+    self.is_identifier() || matches!(self.peek_kind(), Some(
+      | TokenKind::Numeric(_)
+      | TokenKind::String(_)
+      | TokenKind::Char(_)
+      | TokenKind::ByteChar(_)
+      | TokenKind::PipePlaceholder
+      | TokenKind::Punctuation(Punctuation::DoubleColon)
+      | TokenKind::Keyword(Keyword::True | Keyword::False | Keyword::This | Keyword::Super)
+      | TokenKind::Operator(
+        | Operator::BitNot
+        | Operator::LogicalNot
+        | Operator::Minus
+        | Operator::Ampersand
+        | Operator::LogicalAnd
+      )
+      | TokenKind::Grouping(Grouping::OpenParenthesis | Grouping::OpenBrace)
+    ))
+  }
+}
@@ -0,0 +1,205 @@
+use crate::{Result, ok};
+use crate::asterizer::{
+  Asterizer,
+  Function,
+  FunctionBody,
+  Parameter,
+  Receiver,
+  ReceiverKind,
+  Test,
+  error::*,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Operator,
+  Punctuation,
+  Span,
+  TokenKind,
+  error::What,
+};
+
+impl FunctionBody {
+  fn span(&self) -> Span {
+    match self {
+      FunctionBody::Block(block) => block.span,
+      FunctionBody::InlineLlvm(_, span) => *span,
+    }
+  }
+}
+
+impl Asterizer {
+  /// Reads `name -> Ret: arg: T, arg2: U { body }`, up to but not including
+  /// the `;` after it.  Only `extern` and `abstract` functions can go without
+  /// a body, unless `body_optional` is set, like in an interface.
+  pub(in crate::asterizer) fn function(&mut self, body_optional: bool) -> Result<Function> {
+    trace!("Asterizer::function");
+
+    let is_extern = self.eat(Keyword::Extern).is_some();
+    let is_abstract = self.eat(Keyword::Abstract).is_some();
+
+    let name = self.identifier()?;
+
+    let return_type = match self.eat(Punctuation::RightArrow) {
+      Some(_) => Some(self.ty()?),
+      None => None,
+    };
+
+    let (receiver, parameters, variadic) = match self.eat(Punctuation::Colon) {
+      Some(_) => {
+        let receiver = self.receiver();
+
+        // the other parameters come after a `,` following the receiver
+        let (parameters, variadic) = if receiver.is_none() || self.eat(Punctuation::Comma).is_some() {
+          self.parameters()?
+        } else {
+          (vec![], None)
+        };
+
+        (receiver, parameters, variadic)
+      },
+      None => (None, vec![], None),
+    };
+
+    if let Some(span) = variadic.filter(|_| !is_extern) {
+      return ExpectedSnafu { what: What::Parameter, found: What::Exactly(Punctuation::Ellipsis.to_str()), span }.fail()?;
+    };
+
+    let body = self.function_body()?;
+
+    self.check_body(&body, is_extern || is_abstract, body_optional)?;
+
+    Ok(Function {
+      name,
+      is_extern,
+      is_abstract,
+      return_type,
+      receiver,
+      parameters,
+      is_variadic: variadic.is_some(),
+      body,
+    })
+  }
+
+  fn check_body(&self, body: &Option<FunctionBody>, bodiless: bool, optional: bool) -> Result {
+    match (body, bodiless) {
+      (Some(body), true) => {
+        let semicolon = What::Exactly(Punctuation::Semicolon.to_str());
+
+        ExpectedSnafu { what: semicolon, found: What::FunctionBody, span: body.span() }.fail()?
+      },
+      (None, false) if !optional => self.expected(What::FunctionBody),
+      _ => ok,
+    }
+  }
+
+  /// Reads `this`, `mut this`, `&this` or `&mut this` if one of them comes
+  /// next.
+  fn receiver(&mut self) -> Option<Receiver> {
+    let reference = self.is(Operator::Ampersand);
+    let mutable = matches!(self.peek_nth_kind(reference as usize), Some(TokenKind::Keyword(Keyword::Mut)));
+    let this = reference as usize + mutable as usize;
+
+    if !matches!(self.peek_nth_kind(this), Some(TokenKind::Keyword(Keyword::This))) {
+      return None;
+    };
+
+    let start = self.peek_span();
+
+    for _ in 0..=this {
+      self.seek();
+    };
+
+    let kind = match (reference, mutable) {
+      (false, false) => ReceiverKind::Value,
+      (false, true) => ReceiverKind::MutableValue,
+      (true, false) => ReceiverKind::Reference,
+      (true, true) => ReceiverKind::MutableReference,
+    };
+
+    Some(Receiver {
+      kind,
+      span: start.to(self.previous_span()),
+    })
+  }
+
+  /// Reads the parameters, along with the span of the `...` that may end
+  /// them.
+  fn parameters(&mut self) -> Result<(Vec<Parameter>, Option<Span>)> {
+    let mut parameters = vec![];
+
+    loop {
+      if let Some(span) = self.eat(Punctuation::Ellipsis) {
+        return Ok((parameters, Some(span)));
+      };
+
+      parameters.push(self.parameter()?);
+
+      if self.eat(Punctuation::Comma).is_none() {
+        break;
+      };
+    };
+
+    Ok((parameters, None))
+  }
+
+  fn parameter(&mut self) -> Result<Parameter> {
+    trace!("Asterizer::parameter");
+
+    if !self.is_identifier() {
+      return self.expected(What::Parameter);
+    };
+
+    let name = self.identifier()?;
+    self.expect(Punctuation::Colon)?;
+    let ty = self.ty()?;
+
+    Ok(Parameter {
+      span: name.span.to(ty.span),
+      name,
+      ty,
+    })
+  }
+
+  /// Reads a block or `__llvm { ... }` body.  A `;` means that there is no
+  /// body, and is left for the declaration to consume.
+  fn function_body(&mut self) -> Result<Option<FunctionBody>> {
+    let span = self.peek_span();
+
+    Ok(match self.peek_kind() {
+      Some(TokenKind::Grouping(Grouping::OpenBrace)) => Some(FunctionBody::Block(self.block()?)),
+      Some(TokenKind::InlineLlvm(ir)) => {
+        let ir = ir.clone();
+        self.seek();
+
+        Some(FunctionBody::InlineLlvm(ir, span))
+      },
+      Some(TokenKind::Punctuation(Punctuation::Semicolon)) => None,
+      _ => return self.expected(What::FunctionBody),
+    })
+  }
+
+  /// Whether a `test name { body }` comes next.  Since `test` is only a
+  /// contextual keyword, `test {}` and `test: a: T {}` are still functions
+  /// named `test`, which have no name after it.
+  pub(in crate::asterizer) fn starts_test(&self) -> bool {
+    let is_name = |kind: Option<&TokenKind>| match kind {
+      Some(TokenKind::Identifier(_)) => true,
+      Some(TokenKind::Keyword(keyword)) => keyword.is_contextual(),
+      _ => false,
+    };
+
+    self.is(Keyword::Test) && is_name(self.peek_nth_kind(1))
+  }
+
+  /// Reads `test name { body }`, up to but not including the `;` after it.
+  pub(in crate::asterizer) fn test(&mut self) -> Result<Test> {
+    trace!("Asterizer::test");
+
+    self.expect(Keyword::Test)?;
+    let name = self.identifier()?;
+    let body = self.block()?;
+
+    Ok(Test { name, body })
+  }
+}
@@ -0,0 +1,71 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Import,
+  ImportItem,
+  ImportKind,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Punctuation,
+  StringKind,
+  TokenKind,
+  error::What,
+};
+
+impl Asterizer {
+  /// Reads `import { a::b, c as d } from "path"` or `import name from
+  /// "path"`, up to but not including the `;` after it.
+  pub(in crate::asterizer) fn import(&mut self) -> Result<Import> {
+    trace!("Asterizer::import");
+
+    self.expect(Keyword::Import)?;
+
+    let kind = if self.eat(Grouping::OpenBrace).is_some() {
+      let mut items = vec![];
+
+      while !self.is(Grouping::CloseBrace) {
+        items.push(self.import_item()?);
+
+        if self.eat(Punctuation::Comma).is_none() {
+          break;
+        };
+      };
+
+      self.expect(Grouping::CloseBrace)?;
+
+      ImportKind::Items(items)
+    } else {
+      ImportKind::Module(self.identifier()?)
+    };
+
+    self.expect(Keyword::From)?;
+
+    let source_span = self.peek_span();
+
+    let Some(TokenKind::String(StringKind::Plain(source))) = self.peek_kind() else {
+      return self.expected(What::String);
+    };
+
+    let source = source.clone();
+    self.seek();
+
+    Ok(Import { kind, source, source_span })
+  }
+
+  fn import_item(&mut self) -> Result<ImportItem> {
+    let path = self.path()?;
+
+    let alias = match self.eat(Keyword::As) {
+      Some(_) => Some(self.identifier()?),
+      None => None,
+    };
+
+    Ok(ImportItem {
+      span: path.span.to(self.previous_span()),
+      path,
+      alias,
+    })
+  }
+}
@@ -0,0 +1,32 @@
+pub(crate) mod declaration;
+pub(crate) mod template;
+pub(crate) mod function;
+pub(crate) mod structs;
+pub(crate) mod namespace;
+pub(crate) mod class;
+pub(crate) mod import;
+pub(crate) mod types;
+pub(crate) mod block;
+pub(crate) mod expression;
+pub(crate) mod precedence;
+pub(crate) mod control_flow;
+pub(crate) mod recovery;
+
+use crate::asterizer::Ast;
+use recovery::Resync;
+
+impl crate::asterizer::Asterizer {
+  /// Reads a whole file.  Errors are kept in `errors` rather than returned,
+  /// so that every one of them can be reported at once.
+  pub(in crate::asterizer) fn ast(&mut self) -> Ast {
+    trace!("Asterizer::ast");
+
+    let mut declarations = vec![];
+
+    while self.peek().is_some() {
+      declarations.push(self.declaration_or_error(Resync::TopLevel));
+    };
+
+    Ast { declarations }
+  }
+}
@@ -0,0 +1,46 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Namespace,
+  TypeAlias,
+};
+use crate::asterizer::impls::recovery::Resync;
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Punctuation,
+};
+
+impl Asterizer {
+  /// Reads `namespace name { declarations }`, up to but not including the
+  /// `;` after it.
+  pub(in crate::asterizer) fn namespace(&mut self) -> Result<Namespace> {
+    trace!("Asterizer::namespace");
+
+    self.expect(Keyword::Namespace)?;
+    let name = self.identifier()?;
+    self.expect(Grouping::OpenBrace)?;
+
+    let mut declarations = vec![];
+
+    while self.peek().is_some() && !self.is(Grouping::CloseBrace) {
+      declarations.push(self.declaration_or_error(Resync::InNamespace));
+    };
+
+    self.expect(Grouping::CloseBrace)?;
+
+    Ok(Namespace { name, declarations })
+  }
+
+  /// Reads `type Name := Type`, up to but not including the `;` after it.
+  pub(in crate::asterizer) fn type_alias(&mut self) -> Result<TypeAlias> {
+    trace!("Asterizer::type_alias");
+
+    self.expect(Keyword::Type)?;
+    let name = self.identifier()?;
+    self.expect(Punctuation::Bollocks)?;
+    let ty = self.ty()?;
+
+    Ok(TypeAlias { name, ty })
+  }
+}
@@ -0,0 +1,101 @@
+use crate::tokenizer::Operator;
+
+/// How tightly an infix operator binds, from loosest to tightest.  A cast,
+/// `expression: Type`, binds looser than anything but assignment.  Prefix
+/// operators sit between multiplication and exponentiation, so that `-a ** 2`
+/// is `-(a ** 2)`.  Postfix operators, calls, indexes and member accesses
+/// bind tighter than any of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(in crate::asterizer) enum Precedence {
+  Assignment,
+  Cast,
+  Pipe,
+  LogicalOr,
+  LogicalXor,
+  LogicalAnd,
+  Equality,
+  Comparison,
+  BitOr,
+  BitXor,
+  BitAnd,
+  Shift,
+  Additive,
+  Multiplicative,
+  Prefix,
+  Exponent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::asterizer) enum Associativity {
+  Left,
+  Right,
+}
+
+impl Precedence {
+  /// The precedence that the right-hand side of an operator at this level
+  /// has to bind at least as tightly as.
+  pub(in crate::asterizer) fn right_side(self, associativity: Associativity) -> u8 {
+    match associativity {
+      Associativity::Left => self as u8 + 1,
+      Associativity::Right => self as u8,
+    }
+  }
+}
+
+impl Operator {
+  /// The precedence and associativity of an operator when it is used between
+  /// two operands.  Every operator is listed, so that a new one can't be
+  /// added without deciding how it parses.
+  pub(in crate::asterizer) fn infix(self) -> Option<(Precedence, Associativity)> {
+    use Associativity::*;
+    use Precedence::*;
+
+    Some(match self {
+      | Operator::Assign
+      | Operator::AddAssign
+      | Operator::SubAssign
+      | Operator::MulAssign
+      | Operator::DivAssign
+      | Operator::ModuloAssign
+      | Operator::ExpAssign
+      | Operator::BitAndAssign
+      | Operator::BitOrAssign
+      | Operator::BitXorAssign
+      | Operator::BitShiftLeftAssign
+      | Operator::BitShiftRightAssign
+      | Operator::LogicalShiftRightAssign
+      | Operator::LogicalAndAssign
+      | Operator::LogicalOrAssign
+      | Operator::LogicalXorAssign
+      | Operator::PipeAssign => (Assignment, Right),
+      Operator::Pipe => (Pipe, Left),
+      Operator::LogicalOr => (LogicalOr, Left),
+      Operator::LogicalXor => (LogicalXor, Left),
+      Operator::LogicalAnd => (LogicalAnd, Left),
+      Operator::Equals => (Equality, Left),
+      | Operator::LessThan
+      | Operator::LessThanEquals
+      | Operator::GreaterThan
+      | Operator::GreaterThanEquals => (Comparison, Left),
+      Operator::BitOr => (BitOr, Left),
+      Operator::BitXor => (BitXor, Left),
+      Operator::Ampersand => (BitAnd, Left),
+      | Operator::BitShiftLeft
+      | Operator::BitShiftRight
+      | Operator::LogicalShiftRight => (Shift, Left),
+      | Operator::Plus
+      | Operator::Minus => (Additive, Left),
+      | Operator::Asterisk
+      | Operator::Div
+      | Operator::Modulo => (Multiplicative, Left),
+      Operator::Exp => (Exponent, Right),
+      // these are only ever prefix or postfix
+      | Operator::BitNot
+      | Operator::LogicalNot
+      | Operator::Increment
+      | Operator::Decrement
+      | Operator::Try
+      | Operator::Dot => return None,
+    })
+  }
+}
@@ -0,0 +1,76 @@
+use crate::asterizer::Asterizer;
+use crate::compiler::error::CompilerError;
+use crate::tokenizer::{
+  Grouping,
+  Punctuation,
+  Span,
+  TokenKind,
+};
+
+/// Where parsing can pick up again after an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::asterizer) enum Resync {
+  /// Between the declarations of a file, which stops past a `;` or before
+  /// anything that can only start a declaration.
+  TopLevel,
+  /// Between the declarations of a namespace, which also stops before the
+  /// `}` that closes it.
+  InNamespace,
+  /// Between the statements of a block or the members of a class, which
+  /// stops past a `;` or before the closing `}`.
+  InBraces,
+}
+
+impl Asterizer {
+  /// Keeps an error to report once the whole file has been read.
+  pub(in crate::asterizer) fn report(&mut self, err: impl Into<CompilerError>) {
+    let err = err.into();
+    debug!("Asterizer::report {err:?}");
+
+    self.errors.push(err);
+  }
+
+  /// Reports an error that was hit while reading something that started at
+  /// token `start`, and skips ahead to where parsing can carry on.  Returns
+  /// the span of what was skipped, for the error node that takes its place.
+  pub(in crate::asterizer) fn recover(&mut self, err: CompilerError, start: usize, start_span: Span, resync: Resync) -> Span {
+    self.report(err);
+
+    // braces opened before the error still need to be closed.  parentheses
+    // and brackets are left alone, since a `;` or `}` inside of them most
+    // likely means that one was left unclosed
+    let mut depth = self.tokens[start..self.position].iter()
+      .fold(0usize, |depth, token| match token.kind {
+        TokenKind::Grouping(Grouping::OpenBrace) => depth + 1,
+        TokenKind::Grouping(Grouping::CloseBrace) => depth.saturating_sub(1),
+        _ => depth,
+      });
+
+    while let Some(token) = self.peek() {
+      match token.kind {
+        TokenKind::Punctuation(Punctuation::Semicolon) if depth == 0 => {
+          self.seek();
+          break;
+        },
+        TokenKind::Grouping(Grouping::CloseBrace) if depth == 0 && resync != Resync::TopLevel => break,
+        TokenKind::Keyword(keyword) if depth == 0 && resync != Resync::InBraces && keyword.starts_declaration() => break,
+        TokenKind::Grouping(Grouping::OpenBrace) => depth += 1,
+        TokenKind::Grouping(Grouping::CloseBrace) => depth = depth.saturating_sub(1),
+        _ => {},
+      };
+
+      self.seek();
+    };
+
+    // always make progress, even if the error was at the very first token
+    if self.position == start && self.peek().is_some() {
+      self.seek();
+    };
+
+    if self.position == start {
+      return start_span;
+    };
+
+    start_span.to(self.previous_span())
+  }
+}
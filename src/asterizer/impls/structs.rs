@@ -0,0 +1,82 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Expression,
+  ExpressionKind,
+  Field,
+  FieldValue,
+  Path,
+  Struct,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Punctuation,
+};
+
+impl Asterizer {
+  /// Reads `struct Name { field: Type, ... }`, up to but not including the
+  /// `;` after it.
+  pub(in crate::asterizer) fn structure(&mut self) -> Result<Struct> {
+    trace!("Asterizer::structure");
+
+    self.expect(Keyword::Struct)?;
+    let name = self.identifier()?;
+
+    let fields = self.fields(|this| {
+      let name = this.identifier()?;
+      this.expect(Punctuation::Colon)?;
+      let ty = this.ty()?;
+
+      Ok(Field {
+        span: name.span.to(ty.span),
+        name,
+        ty,
+      })
+    })?;
+
+    Ok(Struct { name, fields })
+  }
+
+  /// Reads the `{ field: value, ... }` after the path of a struct literal.
+  pub(in crate::asterizer) fn struct_literal(&mut self, path: Path) -> Result<Expression> {
+    trace!("Asterizer::struct_literal");
+
+    let fields = self.with_struct_literals(true, |this| this.fields(|this| {
+      let name = this.identifier()?;
+      this.expect(Punctuation::Colon)?;
+      let value = this.expression()?;
+
+      Ok(FieldValue {
+        span: name.span.to(value.span),
+        name,
+        value,
+      })
+    }))?;
+
+    Ok(Expression {
+      span: path.span.to(self.previous_span()),
+      kind: ExpressionKind::StructLiteral { path, fields },
+    })
+  }
+
+  /// Reads a comma-separated list in braces, which may have a trailing
+  /// comma.
+  fn fields<T>(&mut self, mut field: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+    self.expect(Grouping::OpenBrace)?;
+
+    let mut fields = vec![];
+
+    while !self.is(Grouping::CloseBrace) {
+      fields.push(field(self)?);
+
+      if self.eat(Punctuation::Comma).is_none() {
+        break;
+      };
+    };
+
+    self.expect(Grouping::CloseBrace)?;
+
+    Ok(fields)
+  }
+}
@@ -0,0 +1,85 @@
+use crate::Result;
+use crate::asterizer::{
+  Asterizer,
+  Bound,
+  BoundKind,
+  Template,
+  TemplateParameter,
+};
+use crate::tokenizer::{
+  Keyword,
+  Operator,
+  Punctuation,
+  TokenKind,
+};
+
+impl Asterizer {
+  /// Whether a `template:` prefix comes next.  Since `template` is only a
+  /// contextual keyword, `template: a: T {}` is still a function named
+  /// `template`, which tells itself apart by the `:` after its first
+  /// parameter.
+  pub(in crate::asterizer) fn starts_template(&self) -> bool {
+    let is_colon = |kind: Option<&TokenKind>| matches!(kind, Some(TokenKind::Punctuation(Punctuation::Colon)));
+
+    self.is(Keyword::Template)
+      && is_colon(self.peek_nth_kind(1))
+      && !is_colon(self.peek_nth_kind(3))
+  }
+
+  /// Reads `template: T, U extends A;`, including the `;`.
+  pub(in crate::asterizer) fn template(&mut self) -> Result<Template> {
+    trace!("Asterizer::template");
+
+    let start = self.expect(Keyword::Template)?;
+    self.expect(Punctuation::Colon)?;
+
+    let mut parameters = vec![];
+
+    loop {
+      parameters.push(self.template_parameter()?);
+
+      if self.eat(Punctuation::Comma).is_none() {
+        break;
+      };
+    };
+
+    let end = self.expect(Punctuation::Semicolon)?;
+
+    Ok(Template {
+      parameters,
+      span: start.to(end),
+    })
+  }
+
+  fn template_parameter(&mut self) -> Result<TemplateParameter> {
+    let name = self.identifier()?;
+    let mut bounds = vec![];
+
+    while let Some(kind) = self.bound_kind() {
+      self.seek();
+
+      loop {
+        bounds.push(Bound { kind, ty: self.ty()? });
+
+        if self.eat(Operator::Plus).is_none() {
+          break;
+        };
+      };
+    };
+
+    Ok(TemplateParameter {
+      span: name.span.to(self.previous_span()),
+      name,
+      bounds,
+    })
+  }
+
+  fn bound_kind(&self) -> Option<BoundKind> {
+    match self.peek_kind() {
+      Some(TokenKind::Keyword(Keyword::Extends)) => Some(BoundKind::Extends),
+      Some(TokenKind::Keyword(Keyword::Implements)) => Some(BoundKind::Implements),
+      Some(TokenKind::Keyword(Keyword::Satisfies)) => Some(BoundKind::Satisfies),
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,194 @@
+use crate::{Result, ok};
+use crate::asterizer::{
+  Asterizer,
+  Ident,
+  Path,
+  Type,
+  TypeKind,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Operator,
+  Punctuation,
+  Span,
+  TokenKind,
+  error::What,
+};
+
+impl Asterizer {
+  pub(in crate::asterizer) fn ty(&mut self) -> Result<Type> {
+    trace!("Asterizer::ty");
+
+    let start = self.peek_span();
+
+    let kind = match self.peek_kind() {
+      Some(TokenKind::Keyword(Keyword::Void)) => {
+        self.seek();
+        TypeKind::Void
+      },
+      Some(TokenKind::Keyword(Keyword::ThisType)) => {
+        self.seek();
+        TypeKind::This
+      },
+      Some(TokenKind::Operator(Operator::Ampersand | Operator::LogicalAnd)) => return self.reference_type(),
+      Some(TokenKind::Grouping(Grouping::OpenBracket)) => {
+        self.seek();
+
+        let size = match self.is(Grouping::CloseBracket) {
+          true => None,
+          false => Some(Box::new(self.expression()?)),
+        };
+
+        self.expect(Grouping::CloseBracket)?;
+
+        let element = Box::new(self.ty()?);
+
+        match size {
+          Some(size) => TypeKind::Array { size, element },
+          None => TypeKind::Slice(element),
+        }
+      },
+      Some(TokenKind::Keyword(Keyword::Super)) => TypeKind::Path(self.type_path()?),
+      Some(_) if self.is_identifier() => TypeKind::Path(self.type_path()?),
+      _ => return self.expected(What::Type),
+    };
+
+    Ok(Type {
+      kind,
+      span: start.to(self.previous_span()),
+    })
+  }
+
+  /// Reads `&T` or `&mut T`, where `&&T` is a reference to a reference.
+  fn reference_type(&mut self) -> Result<Type> {
+    let start = self.peek_span();
+    let double = self.is(Operator::LogicalAnd);
+    self.seek();
+
+    let mutable = self.eat(Keyword::Mut).is_some();
+    let inner = self.ty()?;
+
+    let inner_start = if double {
+      Span { start: start.start + 1, ..start }
+    } else {
+      start
+    };
+
+    let reference = Type {
+      span: inner_start.to(inner.span),
+      kind: TypeKind::Reference {
+        mutable,
+        inner: Box::new(inner),
+      },
+    };
+
+    if !double {
+      return Ok(reference);
+    };
+
+    Ok(Type {
+      span: start.to(reference.span),
+      kind: TypeKind::Reference {
+        mutable: false,
+        inner: Box::new(reference),
+      },
+    })
+  }
+
+  /// Reads a path in a type, where a `<` always starts generic arguments.
+  fn type_path(&mut self) -> Result<Path> {
+    let mut path = self.path()?;
+
+    if self.eat(Operator::LessThan).is_some() {
+      path.arguments = self.generic_arguments()?;
+      path.span = path.span.to(self.previous_span());
+    };
+
+    Ok(path)
+  }
+
+  /// Reads the types between `<` and `>`, after the `<` has been consumed.
+  pub(in crate::asterizer) fn generic_arguments(&mut self) -> Result<Vec<Type>> {
+    let mut arguments = vec![];
+
+    loop {
+      arguments.push(self.ty()?);
+
+      if self.eat(Punctuation::Comma).is_none() || self.is_closing_angle() {
+        break;
+      };
+    };
+
+    self.expect_closing_angle()?;
+
+    Ok(arguments)
+  }
+
+  fn is_closing_angle(&self) -> bool {
+    matches!(self.peek_kind(), Some(TokenKind::Operator(
+      | Operator::GreaterThan
+      | Operator::GreaterThanEquals
+      | Operator::BitShiftRight
+      | Operator::BitShiftRightAssign
+      | Operator::LogicalShiftRight
+      | Operator::LogicalShiftRightAssign
+    )))
+  }
+
+  /// Consumes the `>` that closes generic arguments.  The tokenizer reads
+  /// `>>` in `A<B<C>>` as one operator, so a token that only starts with `>`
+  /// is split, leaving the rest of it to be read next.
+  fn expect_closing_angle(&mut self) -> Result {
+    let rest = match self.peek_kind() {
+      Some(TokenKind::Operator(Operator::GreaterThan)) => None,
+      Some(TokenKind::Operator(Operator::GreaterThanEquals)) => Some(Operator::Assign),
+      Some(TokenKind::Operator(Operator::BitShiftRight)) => Some(Operator::GreaterThan),
+      Some(TokenKind::Operator(Operator::BitShiftRightAssign)) => Some(Operator::GreaterThanEquals),
+      Some(TokenKind::Operator(Operator::LogicalShiftRight)) => Some(Operator::BitShiftRight),
+      Some(TokenKind::Operator(Operator::LogicalShiftRightAssign)) => Some(Operator::BitShiftRightAssign),
+      _ => return self.expected(What::Exactly(Operator::GreaterThan.to_str())),
+    };
+
+    let Some(rest) = rest else {
+      self.seek();
+      return ok;
+    };
+
+    self.split_operator(1, rest);
+
+    ok
+  }
+
+  /// Reads `a::b::c`, where `super` names the enclosing namespace.
+  pub(in crate::asterizer) fn path(&mut self) -> Result<Path> {
+    let start = self.peek_span();
+    let rooted = self.eat(Punctuation::DoubleColon).is_some();
+
+    let mut segments = vec![self.path_segment()?];
+
+    while self.eat(Punctuation::DoubleColon).is_some() {
+      segments.push(self.path_segment()?);
+    };
+
+    Ok(Path {
+      span: start.to(segments[segments.len() - 1].span),
+      rooted,
+      segments,
+      arguments: vec![],
+    })
+  }
+
+  fn path_segment(&mut self) -> Result<Ident> {
+    let span = self.peek_span();
+
+    if self.eat(Keyword::Super).is_some() {
+      return Ok(Ident {
+        name: self.interner.intern(Keyword::Super.to_str()),
+        span,
+      });
+    };
+
+    self.identifier()
+  }
+}
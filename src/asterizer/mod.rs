@@ -0,0 +1,262 @@
+mod ast;
+pub(crate) mod error;
+
+mod impls;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use ast::*;
+
+use crate::Result;
+use crate::compiler::{
+  Compiler,
+  CompilerWorkflow,
+  Interner,
+  error::CompilerError,
+};
+use crate::tokenizer::{
+  Grouping,
+  Keyword,
+  Operator,
+  Punctuation,
+  Span,
+  Token,
+  TokenKind,
+  error::What,
+};
+
+use error::*;
+
+/// A token that is always spelled the same way, so that it can be matched
+/// and named in errors without looking at its contents.
+pub(in crate::asterizer) trait Exact: Copy {
+  fn matches(self, kind: &TokenKind) -> bool;
+  fn text(self) -> &'static str;
+}
+
+macro_rules! impl_exact {
+  ($($name:ident),+) => {
+    $(
+      impl Exact for $name {
+        fn matches(self, kind: &TokenKind) -> bool {
+          matches!(kind, TokenKind::$name(other) if *other == self)
+        }
+
+        fn text(self) -> &'static str {
+          self.to_str()
+        }
+      }
+    )+
+  };
+}
+
+impl_exact!(Keyword, Operator, Punctuation, Grouping);
+
+pub(crate) struct Asterizer {
+  /// Every token except for whitespace and comments.
+  tokens: Vec<Token>,
+  position: usize,
+  /// The span of the last token that was consumed, which may only be part of
+  /// a token that was split.
+  previous: Option<Span>,
+  /// An empty span at the end of the file, for errors about missing tokens.
+  end: Option<Span>,
+  /// Tokens that were split, along with what they were before, so that
+  /// splitting can be undone when backtracking.
+  splits: Vec<(usize, Operator, Span)>,
+  /// Whether `Name { ... }` may be read as a struct literal, which it can't
+  /// be in the condition of an `if` or a loop, where the `{` starts the body.
+  struct_literals: bool,
+  /// Every error so far, each of which was recovered from.
+  errors: Vec<CompilerError>,
+  interner: Interner,
+}
+
+/// A position to backtrack to, after trying to read something that might not
+/// be there.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::asterizer) struct Snapshot {
+  position: usize,
+  previous: Option<Span>,
+  splits: usize,
+  errors: usize,
+}
+
+impl Asterizer {
+  fn from_tokens(tokens: Vec<Token>, interner: Interner) -> Self {
+    Self {
+      end: tokens.last().map(|token| token.span.after()),
+      tokens: tokens.into_iter()
+        .filter(|token| !token.kind.is_trivia())
+        .collect(),
+      position: 0,
+      previous: None,
+      splits: vec![],
+      struct_literals: true,
+      errors: vec![],
+      interner,
+    }
+  }
+
+  /// Reads something with struct literals allowed or not, going back to what
+  /// was allowed before once done.
+  fn with_struct_literals<T>(&mut self, allowed: bool, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+    let outer = std::mem::replace(&mut self.struct_literals, allowed);
+    let result = read(self);
+    self.struct_literals = outer;
+
+    result
+  }
+
+  fn snapshot(&self) -> Snapshot {
+    Snapshot {
+      position: self.position,
+      previous: self.previous,
+      splits: self.splits.len(),
+      errors: self.errors.len(),
+    }
+  }
+
+  fn restore(&mut self, snapshot: Snapshot) {
+    for (index, operator, span) in self.splits.drain(snapshot.splits..).rev() {
+      self.tokens[index] = Token {
+        kind: TokenKind::Operator(operator),
+        span,
+      };
+    };
+
+    self.position = snapshot.position;
+    self.previous = snapshot.previous;
+    self.errors.truncate(snapshot.errors);
+  }
+
+  /// Consumes the first `length` bytes of the next token, which must be an
+  /// operator, leaving `rest` to be read next.
+  fn split_operator(&mut self, length: usize, rest: Operator) {
+    let token = &mut self.tokens[self.position];
+
+    let TokenKind::Operator(operator) = token.kind else {
+      panic!("only operators can be split");
+    };
+
+    self.splits.push((self.position, operator, token.span));
+    self.previous = Some(Span { end: token.span.start + length, ..token.span });
+
+    token.kind = TokenKind::Operator(rest);
+    token.span.start += length;
+  }
+
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.position)
+  }
+
+  fn peek_kind(&self) -> Option<&TokenKind> {
+    self.peek().map(|token| &token.kind)
+  }
+
+  /// The kind of the token `n` tokens after the next one.
+  fn peek_nth_kind(&self, n: usize) -> Option<&TokenKind> {
+    self.tokens.get(self.position + n).map(|token| &token.kind)
+  }
+
+  /// The span of the next token, or the end of the file if there is none.
+  fn peek_span(&self) -> Span {
+    self.peek()
+      .map(|token| token.span)
+      .or(self.end)
+      .expect("the end of the file is known once there were tokens to read")
+  }
+
+  /// The span of the last token that was consumed.
+  fn previous_span(&self) -> Span {
+    self.previous.expect("a token has been consumed")
+  }
+
+  fn seek(&mut self) {
+    self.previous = self.peek().map(|token| token.span);
+    self.position += 1;
+  }
+
+  fn is(&self, exact: impl Exact) -> bool {
+    self.peek_kind().is_some_and(|kind| exact.matches(kind))
+  }
+
+  /// Consumes the next token if it is `exact`, returning its span.
+  fn eat(&mut self, exact: impl Exact) -> Option<Span> {
+    if !self.is(exact) {
+      return None;
+    };
+
+    let span = self.peek_span();
+    self.seek();
+
+    Some(span)
+  }
+
+  fn expect(&mut self, exact: impl Exact) -> Result<Span> {
+    let Some(span) = self.eat(exact) else {
+      return self.expected(What::Exactly(exact.text()));
+    };
+
+    Ok(span)
+  }
+
+  /// What the next token is, for errors about what was found instead of what
+  /// was expected.
+  fn found(&self) -> What {
+    self.peek_kind().map_or(What::EndOfFile, TokenKind::what)
+  }
+
+  /// Fails with an error about the next token not being `what`.
+  fn expected<T>(&self, what: What) -> Result<T> {
+    ExpectedSnafu { what, found: self.found(), span: self.peek_span() }.fail()?
+  }
+
+  /// Whether the next token can be used as a name, which includes contextual
+  /// keywords.
+  fn is_identifier(&self) -> bool {
+    self.peek_kind().is_some_and(|kind| match kind {
+      TokenKind::Identifier(_) => true,
+      TokenKind::Keyword(keyword) => keyword.is_contextual(),
+      _ => false,
+    })
+  }
+
+  fn identifier(&mut self) -> Result<Ident> {
+    let span = self.peek_span();
+
+    let name = self.tokens.get(self.position)
+      .and_then(|token| token.kind.as_identifier(&mut self.interner));
+
+    let Some(name) = name else {
+      return self.expected(What::Identifier);
+    };
+
+    self.seek();
+
+    Ok(Ident { name, span })
+  }
+}
+
+impl<W: CompilerWorkflow> crate::compiler::Asterize<W> for Asterizer {
+  type In = Vec<Token>;
+  type Out = Ast;
+
+  fn new() -> Self {
+    Self::from_tokens(vec![], Interner::default())
+  }
+
+  fn asterize(self, compiler: &mut Compiler<W>, tokens: Self::In) -> Result<Self::Out> {
+    // names are interned into the compiler's interner, like the tokenizer does
+    let mut asterizer = Self::from_tokens(tokens, std::mem::take(&mut compiler.interner));
+    let ast = asterizer.ast();
+    compiler.interner = asterizer.interner;
+
+    if !asterizer.errors.is_empty() {
+      return Err(asterizer.errors.into());
+    };
+
+    Ok(ast)
+  }
+}
@@ -0,0 +1,1239 @@
+use std::path::Path;
+
+use crate::asterizer::{
+  Ast,
+  Asterizer,
+  Block,
+  BoundKind,
+  DeclarationKind,
+  Expression,
+  ExpressionKind,
+  Function,
+  FunctionBody,
+  ImportKind,
+  Literal,
+  Member,
+  MemberKind,
+  Path as AstPath,
+  PostfixOperator,
+  ReceiverKind,
+  Statement,
+  StatementKind,
+  Type,
+  TypeKind,
+  UnaryOperator,
+  Visibility,
+};
+use crate::compiler::{
+  Interner,
+  SourceMap,
+  error::CompilerError,
+};
+use crate::tokenizer::{
+  self,
+  Grouping,
+  Keyword,
+  NumericKind,
+  Operator,
+  Punctuation,
+  StringKind,
+};
+
+struct Parsed {
+  source_map: SourceMap,
+  interner: Interner,
+  /// What was parsed, including error nodes for anything that wasn't.
+  ast: Ast,
+  errors: Vec<CompilerError>,
+}
+
+impl Parsed {
+  fn ast(&self) -> &Ast {
+    if let Some(err) = self.errors.first() {
+      let location = err.span().map(|span| self.source_map.locate(&span).0);
+
+      panic!("failed to parse: {err} at {location:?}");
+    };
+
+    &self.ast
+  }
+
+  /// The message and text of every error that was found.
+  fn errors(&self) -> Vec<(String, &str)> {
+    self.errors.iter()
+      .map(|err| {
+        let span = err.span().unwrap();
+        let source = self.source_map.source(span.file);
+
+        (err.to_string(), &source[span.start..span.end])
+      })
+      .collect()
+  }
+
+  /// The message and text of the first error that was found.
+  fn error(&self) -> (String, &str) {
+    self.errors().into_iter().next().expect("parsed without errors")
+  }
+
+  /// The function that is the `index`th declaration.
+  fn function(&self, index: usize) -> &Function {
+    let DeclarationKind::Function(function) = &self.ast().declarations[index].kind else {
+      panic!("expected a function");
+    };
+
+    function
+  }
+
+  fn body(&self, index: usize) -> &Block {
+    let Some(FunctionBody::Block(body)) = &self.function(index).body else {
+      panic!("expected a body");
+    };
+
+    body
+  }
+
+  fn name(&self, symbol: crate::compiler::Symbol) -> &str {
+    self.interner.resolve(symbol)
+  }
+
+  fn dump_path(&self, path: &AstPath) -> String {
+    let mut dump = path.segments.iter()
+      .map(|segment| self.name(segment.name))
+      .collect::<Vec<_>>()
+      .join("::");
+
+    if path.rooted {
+      dump = format!("::{dump}");
+    };
+
+    if !path.arguments.is_empty() {
+      let arguments = path.arguments.iter()
+        .map(|argument| self.dump_type(argument))
+        .collect::<Vec<_>>();
+
+      dump = format!("{dump}<{}>", arguments.join(", "));
+    };
+
+    dump
+  }
+
+  fn dump_type(&self, ty: &Type) -> String {
+    match &ty.kind {
+      TypeKind::Void => "void".into(),
+      TypeKind::This => "This".into(),
+      TypeKind::Path(path) => self.dump_path(path),
+      TypeKind::Reference { mutable: false, inner } => format!("&{}", self.dump_type(inner)),
+      TypeKind::Reference { mutable: true, inner } => format!("&mut {}", self.dump_type(inner)),
+      TypeKind::Slice(element) => format!("[]{}", self.dump_type(element)),
+      TypeKind::Array { size, element } => format!("[{}]{}", self.dump(size), self.dump_type(element)),
+    }
+  }
+
+  fn dump_statement(&self, statement: &Statement) -> String {
+    match &statement.kind {
+      StatementKind::Expression(expression) => format!("{};", self.dump(expression)),
+      StatementKind::Error => "<error>;".into(),
+      StatementKind::Local(local) => {
+        let mut dump = format!("(let {}{}", if local.mutable { "mut " } else { "" }, self.name(local.name.name));
+
+        if let Some(ty) = &local.ty {
+          dump = format!("{dump}: {}", self.dump_type(ty));
+        };
+
+        if let Some(value) = &local.value {
+          dump = format!("{dump} = {}", self.dump(value));
+        };
+
+        format!("{dump});")
+      },
+    }
+  }
+
+  fn dump_block(&self, block: &Block) -> String {
+    let mut parts = block.statements.iter()
+      .map(|statement| self.dump_statement(statement))
+      .collect::<Vec<_>>();
+
+    if let Some(tail) = &block.tail {
+      parts.push(self.dump(tail));
+    };
+
+    format!("{{{}}}", parts.join(" "))
+  }
+
+  /// Writes an expression as an S-expression, which makes the shape of the
+  /// tree easy to compare.
+  fn dump(&self, expression: &Expression) -> String {
+    match &expression.kind {
+      ExpressionKind::Literal(literal) => match literal {
+        Literal::Numeric(NumericKind::Integer(value, _)) => value.to_string(),
+        Literal::Numeric(NumericKind::Float(value, _)) => format!("{value:?}"),
+        Literal::String(StringKind::Plain(string)) => format!("{string:?}"),
+        Literal::String(StringKind::Byte(bytes)) => format!("b{:?}", String::from_utf8_lossy(bytes)),
+        Literal::String(StringKind::C(bytes)) => format!("c{:?}", String::from_utf8_lossy(bytes)),
+        Literal::Char(ch) => format!("{ch:?}"),
+        Literal::ByteChar(byte) => format!("b{:?}", *byte as char),
+        Literal::Bool(value) => value.to_string(),
+      },
+      ExpressionKind::Path(path) => self.dump_path(path),
+      ExpressionKind::Block(block) => self.dump_block(block),
+      ExpressionKind::Placeholder => "$".into(),
+      ExpressionKind::This => "this".into(),
+      ExpressionKind::Unary { operator, operand } => {
+        let operator = match operator {
+          UnaryOperator::BitNot => "~",
+          UnaryOperator::LogicalNot => "!",
+          UnaryOperator::Negate => "-",
+          UnaryOperator::Reference => "&",
+          UnaryOperator::MutableReference => "&mut",
+        };
+
+        format!("({operator} {})", self.dump(operand))
+      },
+      ExpressionKind::Postfix { operator, operand } => {
+        let operator = match operator {
+          PostfixOperator::Increment => "++",
+          PostfixOperator::Decrement => "--",
+          PostfixOperator::Try => "?",
+        };
+
+        format!("({} {operator})", self.dump(operand))
+      },
+      ExpressionKind::Binary { operator, left, right } => {
+        format!("({} {} {})", operator.to_str(), self.dump(left), self.dump(right))
+      },
+      ExpressionKind::Member { object, member, arrow } => {
+        let operator = if *arrow { "->" } else { "." };
+
+        format!("({operator} {} {})", self.dump(object), self.name(member.name))
+      },
+      ExpressionKind::Call { callee, arguments } => {
+        let mut parts = vec!["call".to_owned(), self.dump(callee)];
+        parts.extend(arguments.iter().map(|argument| self.dump(argument)));
+
+        format!("({})", parts.join(" "))
+      },
+      ExpressionKind::Index { object, index } => {
+        format!("(index {} {})", self.dump(object), self.dump(index))
+      },
+      ExpressionKind::Cast { expression, ty } => {
+        format!("(: {} {})", self.dump(expression), self.dump_type(ty))
+      },
+      ExpressionKind::If { negated, condition, then, otherwise } => {
+        let keyword = if *negated { "unless" } else { "if" };
+        let mut parts = vec![keyword.to_owned(), self.dump(condition), self.dump_block(then)];
+        parts.extend(otherwise.iter().map(|otherwise| self.dump(otherwise)));
+
+        format!("({})", parts.join(" "))
+      },
+      ExpressionKind::While { negated, body_first, condition, body } => {
+        let keyword = match (body_first, negated) {
+          (false, false) => "while",
+          (false, true) => "until",
+          (true, false) => "do-while",
+          (true, true) => "do-until",
+        };
+
+        format!("({keyword} {} {})", self.dump(condition), self.dump_block(body))
+      },
+      ExpressionKind::Loop(body) => format!("(loop {})", self.dump_block(body)),
+      ExpressionKind::For { binding, iterable, body } => {
+        format!("(for {} {} {})", self.name(binding.name), self.dump(iterable), self.dump_block(body))
+      },
+      ExpressionKind::Switch { value, arms } | ExpressionKind::Match { value, arms } => {
+        let keyword = if matches!(expression.kind, ExpressionKind::Switch { .. }) { "switch" } else { "match" };
+        let mut parts = vec![keyword.to_owned(), self.dump(value)];
+        parts.extend(arms.iter().map(|arm| format!("({} {})", self.dump(&arm.pattern), self.dump(&arm.value))));
+
+        format!("({})", parts.join(" "))
+      },
+      ExpressionKind::Break(None) => "break".into(),
+      ExpressionKind::Break(Some(value)) => format!("(break {})", self.dump(value)),
+      ExpressionKind::Continue => "continue".into(),
+      ExpressionKind::Return(None) => "return".into(),
+      ExpressionKind::Return(Some(value)) => format!("(return {})", self.dump(value)),
+      ExpressionKind::StructLiteral { path, fields } => {
+        let mut parts = vec!["struct".to_owned(), self.dump_path(path)];
+        parts.extend(fields.iter().map(|field| format!("({} {})", self.name(field.name.name), self.dump(&field.value))));
+
+        format!("({})", parts.join(" "))
+      },
+    }
+  }
+}
+
+/// The method that a member declares, which it has unless it failed to
+/// parse.
+fn method(member: &Member) -> &Function {
+  let MemberKind::Method(function) = &member.kind else {
+    panic!("member failed to parse");
+  };
+
+  function
+}
+
+fn parse_file(source_map: SourceMap, file: crate::compiler::FileId) -> Parsed {
+  let mut interner = Interner::default();
+
+  let tokens = tokenizer::tokenize_file(&source_map, file, &mut interner)
+    .unwrap_or_else(|err| panic!("failed to tokenize: {err}"));
+
+  let mut asterizer = Asterizer::from_tokens(tokens, interner);
+
+  let ast = asterizer.ast();
+
+  Parsed {
+    source_map,
+    interner: asterizer.interner,
+    ast,
+    errors: asterizer.errors,
+  }
+}
+
+fn parse(source: &str) -> Parsed {
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("test.zy".into(), source.into());
+
+  parse_file(source_map, file)
+}
+
+/// Parses a single expression, by wrapping it up as the value of a function.
+fn parse_expression(source: &str) -> String {
+  let parsed = parse(&format!("f {{ {source} }};"));
+
+  let body = parsed.body(0);
+
+  assert!(body.statements.is_empty(), "{source:?} is more than one expression");
+
+  parsed.dump(body.tail.as_ref().expect("expected a value"))
+}
+
+fn parse_snippet(name: &str) -> Parsed {
+  let mut source_map = SourceMap::new();
+  let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("snippets").join(name);
+  let file = source_map.load(&path).unwrap();
+
+  parse_file(source_map, file)
+}
+
+#[test]
+fn function_declarations() {
+  let parsed = parse("\
+    main -> i32 { 0 };\n\
+    extern puts -> i32: s: c_str;\n\
+    abstract nothing;\n\
+    hello: x: a, y: b::c {};\n\
+    add -> u8: lhs: u8, rhs: u8 __llvm { ret u8 0 };\n\
+  ");
+
+  let functions = parsed.ast().declarations.iter()
+    .map(|declaration| match &declaration.kind {
+      DeclarationKind::Function(function) => function,
+      _ => panic!("expected a function"),
+    })
+    .collect::<Vec<_>>();
+
+  let summary = functions.iter()
+    .map(|function| (
+      parsed.name(function.name.name),
+      function.is_extern,
+      function.is_abstract,
+      function.return_type.is_some(),
+      function.parameters.len(),
+      match &function.body {
+        Some(FunctionBody::Block(_)) => "block",
+        Some(FunctionBody::InlineLlvm(..)) => "llvm",
+        None => "none",
+      },
+    ))
+    .collect::<Vec<_>>();
+
+  assert_eq!(summary, [
+    ("main", false, false, true, 0, "block"),
+    ("puts", true, false, true, 1, "none"),
+    ("nothing", false, true, false, 0, "none"),
+    ("hello", false, false, false, 2, "block"),
+    ("add", false, false, true, 2, "llvm"),
+  ]);
+
+  let TypeKind::Path(path) = &functions[3].parameters[1].ty.kind else {
+    panic!("expected a path type");
+  };
+
+  let segments = path.segments.iter()
+    .map(|segment| parsed.name(segment.name))
+    .collect::<Vec<_>>();
+
+  assert_eq!(segments, ["b", "c"]);
+}
+
+#[test]
+fn base_main() {
+  let parsed = parse_snippet("00_base_main.zy");
+  let ast = parsed.ast();
+
+  assert_eq!(ast.declarations.len(), 2);
+
+  let body = parsed.body(0);
+
+  assert_eq!(body.statements.len(), 2);
+
+  // the innermost `{ 0 }` is the value of the last block, which is the value
+  // of the function
+  let Some(ExpressionKind::Block(last)) = body.tail.as_ref().map(|tail| &tail.kind) else {
+    panic!("expected a block as the tail");
+  };
+
+  let Some(ExpressionKind::Block(zero)) = last.tail.as_ref().map(|tail| &tail.kind) else {
+    panic!("expected a block as the tail");
+  };
+
+  assert!(matches!(zero.tail.as_ref().map(|tail| &tail.kind), Some(ExpressionKind::Literal(_))));
+
+  parse_snippet("bare_bones.zy").ast();
+}
+
+#[test]
+fn function_errors() {
+  let cases = [
+    ("main -> { 0 };", "Syntax error: expected a type, found `{`", "{"),
+    ("extern f -> i32 {};", "Syntax error: expected `;`, found a function body", "{}"),
+    ("f -> i32: a: i32", "Syntax error: expected a function body, found the end of the file", ""),
+    ("f: 1 {};", "Syntax error: expected a parameter, found an integer", "1"),
+    ("main {}", "Syntax error: expected `};` at the end of the declaration, found the end of the file", ""),
+    ("main { 1 2 };", "Syntax error: expected `;` between statements, found an integer", ""),
+    ("1;", "Syntax error: expected a declaration, found an integer", "1"),
+  ];
+
+  for (source, message, text) in cases {
+    let parsed = parse(source);
+
+    assert_eq!(parsed.error(), (message.to_owned(), text), "{source:?}");
+  };
+}
+
+/// Dumps the body of the only function in `source`.
+fn parse_body(source: &str) -> String {
+  let parsed = parse(source);
+
+  let body = parsed.body(0);
+
+  parsed.dump_block(body)
+}
+
+#[test]
+fn statements_and_tails() {
+  let cases = [
+    ("f { a := 1; mut b: i32 := 2; };", "{(let a = 1); (let mut b: i32 = 2);}"),
+    ("f { c: S; mut d: &str; mut e; };", "{(let c: S); (let mut d: &str); (let mut e);}"),
+    ("f { x := ftell(f): usize; x };", "{(let x = (: (call ftell f) usize)); x}"),
+    ("f { ctr: i32 };", "{(: ctr i32)}"),
+    ("f { string[n] = 0; };", "{(= (index string n) 0);}"),
+    ("f { a: u8 + 1: u16; };", "{(: (+ (: a u8) 1) u16);}"),
+    ("f { {}; { 0 } };", "{{}; {0}}"),
+  ];
+
+  for (source, dump) in cases {
+    assert_eq!(parse_body(source), dump, "{source:?}");
+  };
+}
+
+#[test]
+fn statement_errors() {
+  let cases = [
+    ("main { a := 1 b := 2 };", "Syntax error: expected `;` between statements, found an identifier", ""),
+    ("main { f() g() };", "Syntax error: expected `;` between statements, found an identifier", ""),
+    ("main { 0 }", "Syntax error: expected `};` at the end of the declaration, found the end of the file", ""),
+    ("main { 0 }\nother {};", "Syntax error: expected `};` at the end of the declaration, found an identifier", ""),
+    ("main { 1 := 2; };", "Syntax error: expected an identifier, found an expression", "1"),
+    ("main { a.b: T := 2; };", "Syntax error: expected an identifier, found an expression", "a.b: T"),
+  ];
+
+  for (source, message, text) in cases {
+    let parsed = parse(source);
+
+    assert_eq!(parsed.error(), (message.to_owned(), text), "{source:?}");
+  };
+}
+
+#[test]
+fn control_flow() {
+  let cases = [
+    ("if a { 1 } else if b { 2 } else { 3 }", "(if a {1} (if b {2} {3}))"),
+    ("unless a { b } else unless c {}", "(unless a {b} (unless c {}))"),
+    ("if ch > b' ' && ch < b'~' { acc += ch; }", "(if (&& (> ch b' ') (< ch b'~')) {(+= acc ch);})"),
+    ("while ctr < text.len() { ctr++; }", "(while (< ctr (call (. text len))) {(ctr ++);})"),
+    ("until 17 + 6 { A++; }", "(until (+ 17 6) {(A ++);})"),
+    ("do { true } while false", "(do-while false {true})"),
+    ("do { 1 + 1; } until false", "(do-until false {(+ 1 1);})"),
+    ("loop { break 5; }", "(loop {(break 5);})"),
+    ("loop { break; }", "(loop {break;})"),
+    ("loop { continue }", "(loop {continue})"),
+    ("for item in items { f(item); }", "(for item items {(call f item);})"),
+    ("switch x { 1 => a, 2 => { b } 3 => c }", "(switch x (1 a) (2 {b}) (3 c))"),
+    ("match x { _ => 0, }", "(match x (_ 0))"),
+    ("{ return a + 1; }", "{(return (+ a 1));}"),
+    ("{ return }", "{return}"),
+  ];
+
+  for (source, tree) in cases {
+    assert_eq!(parse_expression(source), tree, "{source:?}");
+  };
+
+  assert_eq!(
+    parse_body("f { x := if a { 1 } else { 2 }; x };"),
+    "{(let x = (if a {1} {2})); x}",
+  );
+}
+
+#[test]
+fn control_flow_snippets() {
+  parse_snippet("18_control_flow.zy").ast();
+  parse_snippet("if.zy").ast();
+}
+
+#[test]
+fn recovers_from_every_error() {
+  let parsed = parse("\
+    main {\n\
+      a := ;\n\
+      b := 1;\n\
+      c := (2 + ;\n\
+      d\n\
+    };\n\
+    struct S { a: u8 b: u8 };\n\
+    f -> { 0 };\n\
+    type T := u8;\n\
+    };\n\
+    good {\n\
+      x := 1 y := 2;\n\
+      { z }\n\
+    }\n\
+    class C { f; g {}; public 1; h {}; };\n\
+    namespace n { 1; i {}; };\n\
+  ");
+
+  assert_eq!(parsed.errors(), [
+    ("Syntax error: expected an expression, found `;`".to_owned(), ";"),
+    ("Syntax error: expected an expression, found `;`".to_owned(), ";"),
+    ("Syntax error: expected `}`, found an identifier".to_owned(), "b"),
+    ("Syntax error: expected a type, found `{`".to_owned(), "{"),
+    ("Syntax error: expected a declaration, found `}`".to_owned(), "}"),
+    ("Syntax error: expected `;` between statements, found an identifier".to_owned(), ""),
+    ("Syntax error: expected `};` at the end of the declaration, found `class`".to_owned(), ""),
+    ("Syntax error: expected a function body, found `;`".to_owned(), ";"),
+    ("Syntax error: expected an identifier, found an integer".to_owned(), "1"),
+    ("Syntax error: expected a declaration, found an integer".to_owned(), "1"),
+  ]);
+
+  let kinds = parsed.ast.declarations.iter()
+    .map(|declaration| match &declaration.kind {
+      DeclarationKind::Function(function) => parsed.name(function.name.name).to_owned(),
+      DeclarationKind::TypeAlias(_) => "type".to_owned(),
+      DeclarationKind::Class(class) => {
+        let errors = class.members.iter()
+          .filter(|member| matches!(member.kind, MemberKind::Error))
+          .count();
+
+        format!("class with {} and {errors} errors", class.members.len())
+      },
+      DeclarationKind::Namespace(namespace) => format!("namespace with {}", namespace.declarations.len()),
+      DeclarationKind::Error => "error".to_owned(),
+      _ => panic!("unexpected declaration"),
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(kinds, ["main", "error", "error", "type", "error", "good", "class with 4 and 2 errors", "namespace with 2"]);
+
+  let bodies = [0, 5].map(|index| match &parsed.ast.declarations[index].kind {
+    DeclarationKind::Function(Function { body: Some(FunctionBody::Block(body)), .. }) => parsed.dump_block(body),
+    _ => panic!("expected a body"),
+  });
+
+  assert_eq!(bodies, [
+    "{<error>; (let b = 1); <error>; d}",
+    "{(let x = 1); (let y = 2); {z}}",
+  ]);
+}
+
+#[test]
+fn unclosed_block() {
+  let parsed = parse("main { a := 1;");
+
+  assert_eq!(parsed.errors(), [
+    ("Syntax error: expected `}`, found the end of the file".to_owned(), ""),
+  ]);
+}
+
+#[test]
+fn extended_operators_golden() {
+  let parsed = parse_snippet("04_extended_operators.zy");
+  let block = parsed.body(1);
+
+  let mut lines = block.statements.iter()
+    .map(|statement| parsed.dump_statement(statement))
+    .collect::<Vec<_>>();
+
+  lines.extend(block.tail.iter().map(|tail| parsed.dump(tail)));
+
+  assert_eq!(lines, [
+    "(+ (+ a (/ (* b c) 2)) (* (* e m) (** c 2)));",
+    "(= a (-> (. (. (. a b) c) d) c));",
+    "(a ++);",
+    "(call a);",
+    "0",
+  ]);
+}
+
+#[test]
+fn module_declarations() {
+  let parsed = parse("\
+    type c_str := &[]u8;\n\
+    struct Pair { a: u8, b: c_str, };\n\
+    struct Empty {};\n\
+    namespace outer {\n\
+      export namespace inner {\n\
+        export do_stuff {};\n\
+      };\n\
+    };\n\
+    extern printf -> i32: fmt: &c_str, ...;\n\
+  ");
+
+  let declarations = &parsed.ast().declarations;
+
+  let DeclarationKind::TypeAlias(alias) = &declarations[0].kind else {
+    panic!("expected a type alias");
+  };
+
+  assert_eq!((parsed.name(alias.name.name), parsed.dump_type(&alias.ty).as_str()), ("c_str", "&[]u8"));
+
+  let DeclarationKind::Struct(pair) = &declarations[1].kind else {
+    panic!("expected a struct");
+  };
+
+  let fields = pair.fields.iter()
+    .map(|field| format!("{}: {}", parsed.name(field.name.name), parsed.dump_type(&field.ty)))
+    .collect::<Vec<_>>();
+
+  assert_eq!(fields, ["a: u8", "b: c_str"]);
+  assert!(matches!(&declarations[2].kind, DeclarationKind::Struct(empty) if empty.fields.is_empty()));
+
+  let DeclarationKind::Namespace(outer) = &declarations[3].kind else {
+    panic!("expected a namespace");
+  };
+
+  let DeclarationKind::Namespace(inner) = &outer.declarations[0].kind else {
+    panic!("expected a nested namespace");
+  };
+
+  assert!(!declarations[3].exported);
+  assert!(outer.declarations[0].exported);
+  assert_eq!(parsed.name(inner.name.name), "inner");
+  assert!(inner.declarations[0].exported);
+
+  let printf = parsed.function(4);
+
+  assert!(printf.is_extern && printf.is_variadic);
+  assert_eq!(printf.parameters.len(), 1);
+}
+
+#[test]
+fn declaration_snippets() {
+  for name in [
+    "02_hello_world.zy",
+    "06_type_alias.zy",
+    "07_struct_stuff.zy",
+    "08_codegen.zy",
+    "std/libc.zy",
+  ] {
+    parse_snippet(name).ast();
+  };
+}
+
+#[test]
+fn declaration_errors() {
+  let cases = [
+    ("f: a: i32, ... {};", "Syntax error: expected a parameter, found `...`", "..."),
+    ("struct S { a: u8 }", "Syntax error: expected `};` at the end of the declaration, found the end of the file", ""),
+    ("namespace n { f {};", "Syntax error: expected `}`, found the end of the file", ""),
+    ("struct S { a: u8 b: u8 };", "Syntax error: expected `}`, found an identifier", "b"),
+    ("type T = u8;", "Syntax error: expected `:=`, found `=`", "="),
+    ("export 1;", "Syntax error: expected a declaration, found an integer", "1"),
+  ];
+
+  for (source, message, text) in cases {
+    let parsed = parse(source);
+
+    assert_eq!(parsed.error(), (message.to_owned(), text), "{source:?}");
+  };
+}
+
+#[test]
+fn templates() {
+  let parsed = parse("\
+    template: T;\n\
+    struct Slice { ptr: &[]T, len: usize };\n\
+    template:\n  T extends std::ops::Multiply, U implements A + B satisfies C<T>;\n\
+    export double -> T: a: T, b: U {};\n\
+    template: a: i32 {};\n\
+    namespace n {\n\
+      template: T;\n\
+      f {};\n\
+    };\n\
+  ");
+
+  let templates = parsed.ast().declarations.iter()
+    .map(|declaration| declaration.template.as_ref().map(|template| {
+      template.parameters.iter()
+        .map(|parameter| {
+          let mut dump = parsed.name(parameter.name.name).to_owned();
+
+          for bound in &parameter.bounds {
+            let keyword = match bound.kind {
+              BoundKind::Extends => "extends",
+              BoundKind::Implements => "implements",
+              BoundKind::Satisfies => "satisfies",
+            };
+
+            dump = format!("{dump} {keyword} {}", parsed.dump_type(&bound.ty));
+          };
+
+          dump
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+    }))
+    .collect::<Vec<_>>();
+
+  assert_eq!(templates, [
+    Some("T".to_owned()),
+    Some("T extends std::ops::Multiply, U implements A implements B satisfies C<T>".to_owned()),
+    None,
+    None,
+  ]);
+
+  // a function named `template` is still a function
+  assert_eq!(parsed.name(parsed.function(2).name.name), "template");
+
+  let DeclarationKind::Namespace(namespace) = &parsed.ast().declarations[3].kind else {
+    panic!("expected a namespace");
+  };
+
+  assert!(namespace.declarations[0].template.is_some());
+
+  parse_snippet("14_slice.zy").ast();
+}
+
+#[test]
+fn template_parameters() {
+  let parsed = parse("\
+    template: A, B extends Base, C implements I<A> + J, D satisfies Sized, E extends Base implements I satisfies Sized;\n\
+    f {};\n\
+  ");
+
+  let template = parsed.ast().declarations[0].template.as_ref().unwrap();
+
+  let parameters = template.parameters.iter()
+    .map(|parameter| {
+      let bounds = parameter.bounds.iter()
+        .map(|bound| (bound.kind, parsed.dump_type(&bound.ty)))
+        .collect::<Vec<_>>();
+
+      (parsed.name(parameter.name.name), bounds)
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(parameters, [
+    ("A", vec![]),
+    ("B", vec![(BoundKind::Extends, "Base".to_owned())]),
+    ("C", vec![(BoundKind::Implements, "I<A>".to_owned()), (BoundKind::Implements, "J".to_owned())]),
+    ("D", vec![(BoundKind::Satisfies, "Sized".to_owned())]),
+    ("E", vec![
+      (BoundKind::Extends, "Base".to_owned()),
+      (BoundKind::Implements, "I".to_owned()),
+      (BoundKind::Satisfies, "Sized".to_owned()),
+    ]),
+  ]);
+}
+
+#[test]
+fn template_errors() {
+  let cases = [
+    ("template: T;", "Syntax error: expected a declaration after the template, found the end of the file", "template: T;"),
+    ("namespace n { template: T; };", "Syntax error: expected a declaration after the template, found `}`", "template: T;"),
+    ("template: T;\ntemplate: U;\nf {};", "Syntax error: expected a declaration after the template, found `template`", "template: T;"),
+    ("template: T;\n1 + 2;", "Syntax error: expected a declaration after the template, found an integer", "template: T;"),
+    ("template: T;\nexport ;", "Syntax error: expected a declaration after the template, found `;`", "template: T;"),
+    ("namespace n { template: T, U extends A; -> };", "Syntax error: expected a declaration after the template, found `->`", "template: T, U extends A;"),
+    ("template: T extends;\nf {};", "Syntax error: expected a type, found `;`", ";"),
+    ("template: T U;\nf {};", "Syntax error: expected `;`, found an identifier", "U"),
+  ];
+
+  for (source, message, text) in cases {
+    let parsed = parse(source);
+
+    assert_eq!(parsed.error(), (message.to_owned(), text), "{source:?}");
+  };
+}
+
+#[test]
+fn class_members() {
+  let parsed = parse_snippet("19_class_methods.zy");
+
+  let DeclarationKind::Class(class) = &parsed.ast().declarations[0].kind else {
+    panic!("expected a class");
+  };
+
+  let summary = class.members.iter()
+    .map(|member| (
+      parsed.name(method(member).name.name),
+      member.visibility,
+      method(member).is_abstract,
+      method(member).receiver.as_ref().map(|receiver| receiver.kind),
+      method(member).parameters.len(),
+      method(member).body.is_some(),
+    ))
+    .collect::<Vec<_>>();
+
+  assert_eq!(summary, [
+    ("something_abstract_and_protected", Some(Visibility::Protected), true, Some(ReceiverKind::Reference), 0, false),
+    ("something_static", None, false, None, 0, true),
+    ("internal_consumer", Some(Visibility::Private), false, Some(ReceiverKind::Value), 0, true),
+    ("mut_internal_consumer", Some(Visibility::Private), false, Some(ReceiverKind::MutableValue), 0, true),
+    ("modifier", Some(Visibility::Private), true, Some(ReceiverKind::MutableReference), 0, false),
+    ("getter", None, false, Some(ReceiverKind::Reference), 0, true),
+    ("too_many_arguments", None, false, Some(ReceiverKind::Reference), 4, true),
+    ("abstract_static_and_no_arguments", None, true, None, 0, false),
+    ("some_static_method_with_no_return_but_arguments", None, false, None, 1, true),
+  ]);
+
+  let parsed = parse("class C { public static make -> This { ::new() }; get -> i32: &this { this.value }; };");
+
+  let DeclarationKind::Class(class) = &parsed.ast().declarations[0].kind else {
+    panic!("expected a class");
+  };
+
+  assert!(class.members[0].is_static);
+  assert_eq!(class.members[0].visibility, Some(Visibility::Public));
+
+  let bodies = class.members.iter()
+    .map(|member| match &method(member).body {
+      Some(FunctionBody::Block(body)) => parsed.dump_block(body),
+      _ => panic!("expected a body"),
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(bodies, ["{(call ::new)}", "{(. this value)}"]);
+}
+
+#[test]
+fn interfaces_and_impls() {
+  let parsed = parse("\
+    template: T;\n\
+    export interface Add {\n\
+      public __add -> T: lhs: T, rhs: T;\n\
+    };\n\
+    export interface IArithmetic extends Add, Sub<u8>, IMul, IDiv;\n\
+    impl u8: IArithmetic {\n\
+      __add -> u8: lhs: u8, rhs: u8 __llvm { ret u8 0 };\n\
+    };\n\
+    impl &super::str {\n\
+      len -> usize {};\n\
+    };\n\
+  ");
+
+  let declarations = &parsed.ast().declarations;
+
+  let DeclarationKind::Interface(add) = &declarations[0].kind else {
+    panic!("expected an interface");
+  };
+
+  assert!(declarations[0].exported && declarations[0].template.is_some());
+  assert!(add.extends.is_empty());
+  assert!(add.members.as_ref().is_some_and(|members| method(&members[0]).body.is_none()));
+
+  let DeclarationKind::Interface(arithmetic) = &declarations[1].kind else {
+    panic!("expected an interface");
+  };
+
+  let extends = arithmetic.extends.iter()
+    .map(|ty| parsed.dump_type(ty))
+    .collect::<Vec<_>>();
+
+  assert_eq!(extends, ["Add", "Sub<u8>", "IMul", "IDiv"]);
+  assert!(arithmetic.members.is_none());
+
+  let impls = declarations[2..].iter()
+    .map(|declaration| match &declaration.kind {
+      DeclarationKind::Impl(block) => (
+        parsed.dump_type(&block.target),
+        block.interface.as_ref().map(|interface| parsed.dump_type(interface)),
+        block.members.len(),
+      ),
+      _ => panic!("expected an impl block"),
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(impls, [
+    ("u8".to_owned(), Some("IArithmetic".to_owned()), 1),
+    ("&super::str".to_owned(), None, 1),
+  ]);
+
+  parse_snippet("03_trait_imp.zy").ast();
+  parse_snippet("05_counter_ns.zy").ast();
+  parse_snippet("16_namespaces.zy").ast();
+}
+
+#[test]
+fn std_snippet() {
+  let parsed = parse_snippet("std.zy");
+  let declarations = &parsed.ast().declarations;
+
+  let DeclarationKind::Interface(double) = &declarations[6].kind else {
+    panic!("expected an interface");
+  };
+
+  let template = declarations[6].template.as_ref().unwrap();
+  assert_eq!(parsed.dump_type(&template.parameters[0].bounds[0].ty), "std::ops::Multiply");
+
+  let members = double.members.as_ref().unwrap().iter()
+    .map(|member| (parsed.name(method(member).name.name), member.visibility, member.is_mutable))
+    .collect::<Vec<_>>();
+
+  assert_eq!(members, [
+    ("double", Some(Visibility::Public), false),
+    ("double_self", Some(Visibility::Public), true),
+  ]);
+
+  let DeclarationKind::Test(test) = &declarations[7].kind else {
+    panic!("expected a test");
+  };
+
+  assert_eq!(parsed.name(test.name.name), "double");
+  assert_eq!(parsed.dump_block(&test.body), "{(let mut s: f32 = 40); (let b = (call (. s double))); (= s b); (|>= s (call (. $ double)));}");
+
+  // without a name after it, `test` is still a function
+  let parsed = parse("test {};
+test: a: i32 {};");
+  assert_eq!(parsed.name(parsed.function(1).name.name), "test");
+
+  parse_snippet("19_class_methods.zy").ast();
+  parse_snippet("03_trait_imp.zy").ast();
+}
+
+#[test]
+fn member_errors() {
+  let cases = [
+    ("f: &this {};", "Syntax error: expected a parameter, found a receiver", "&this"),
+    ("class C { f; };", "Syntax error: expected a function body, found `;`", ";"),
+    ("interface I { abstract f {}; };", "Syntax error: expected `;`, found a function body", "{}"),
+    ("class C { f {} };", "Syntax error: expected `};` at the end of the declaration, found `}`", ""),
+    ("class C { public 1; };", "Syntax error: expected an identifier, found an integer", "1"),
+    ("impl u8: { };", "Syntax error: expected a type, found `{`", "{"),
+    ("f: this a: i32 {};", "Syntax error: expected a function body, found an identifier", "a"),
+  ];
+
+  for (source, message, text) in cases {
+    let parsed = parse(source);
+
+    assert_eq!(parsed.error(), (message.to_owned(), text), "{source:?}");
+  };
+}
+
+#[test]
+fn member_error_nodes() {
+  let source = "\
+    class C {\n\
+      a {};\n\
+      public static 1 + 2 { nested; };\n\
+      b {};\n\
+    };\n\
+    interface I {\n\
+      c;\n\
+      private d -> { };\n\
+    };\n\
+  ";
+
+  let parsed = parse(source);
+
+  assert_eq!(parsed.errors(), [
+    ("Syntax error: expected an identifier, found an integer".to_owned(), "1"),
+    ("Syntax error: expected a type, found `{`".to_owned(), "{"),
+  ]);
+
+  let members = parsed.ast.declarations.iter()
+    .map(|declaration| {
+      let members = match &declaration.kind {
+        DeclarationKind::Class(class) => &class.members,
+        DeclarationKind::Interface(interface) => interface.members.as_ref().unwrap(),
+        _ => panic!("expected a class or interface"),
+      };
+
+      members.iter()
+        .map(|member| match &member.kind {
+          MemberKind::Method(function) => parsed.name(function.name.name).to_owned(),
+          MemberKind::Error => format!("error {:?}", &source[member.span.start..member.span.end]),
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(members, [
+    vec!["a", "error \"public static 1 + 2 { nested; };\"", "b"],
+    vec!["c", "error \"private d -> { };\""],
+  ]);
+}
+
+#[test]
+fn imports() {
+  let parsed = parse("\
+    import { libc::printf } from \"std/index.zy\";\n\
+    import libc from \"./libc.zy\";\n\
+    export import { a::b as c, d, } from \"x.zy\";\n\
+    import {} from \"empty.zy\";\n\
+  ");
+
+  let imports = parsed.ast().declarations.iter()
+    .map(|declaration| {
+      let DeclarationKind::Import(import) = &declaration.kind else {
+        panic!("expected an import");
+      };
+
+      let names = match &import.kind {
+        ImportKind::Module(name) => parsed.name(name.name).to_owned(),
+        ImportKind::Items(items) => {
+          let items = items.iter()
+            .map(|item| match &item.alias {
+              Some(alias) => format!("{} as {}", parsed.dump_path(&item.path), parsed.name(alias.name)),
+              None => parsed.dump_path(&item.path),
+            })
+            .collect::<Vec<_>>();
+
+          format!("{{{}}}", items.join(", "))
+        },
+      };
+
+      (declaration.exported, names, import.source.as_str())
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(imports, [
+    (false, "{libc::printf}".to_owned(), "std/index.zy"),
+    (false, "libc".to_owned(), "./libc.zy"),
+    (true, "{a::b as c, d}".to_owned(), "x.zy"),
+    (false, "{}".to_owned(), "empty.zy"),
+  ]);
+
+  let DeclarationKind::Import(import) = &parsed.ast().declarations[1].kind else {
+    panic!("expected an import");
+  };
+
+  let span = import.source_span;
+  assert_eq!(&parsed.source_map.source(span.file)[span.start..span.end], "\"./libc.zy\"");
+
+  for name in ["11_import_std.zy", "12_structs.zy", "13_struct_generic.zy", "std/index.zy"] {
+    parse_snippet(name).ast();
+  };
+}
+
+#[test]
+fn import_errors() {
+  let cases = [
+    ("import libc;", "Syntax error: expected `from`, found `;`", ";"),
+    ("import libc from c\"libc.zy\";", "Syntax error: expected a string literal, found a C string literal", "c\"libc.zy\""),
+    ("import { a b } from \"x\";", "Syntax error: expected `}`, found an identifier", "b"),
+    ("import { a as } from \"x\";", "Syntax error: expected an identifier, found `}`", "}"),
+    ("import libc from \"libc.zy\"", "Syntax error: expected `;`, found the end of the file", ""),
+  ];
+
+  for (source, message, text) in cases {
+    let parsed = parse(source);
+
+    assert_eq!(parsed.error(), (message.to_owned(), text), "{source:?}");
+  };
+}
+
+#[test]
+fn struct_literals() {
+  let cases = [
+    ("Struct { value: 337 }", "(struct Struct (value 337))"),
+    ("Something<i32> { value: 16, }", "(struct Something<i32> (value 16))"),
+    ("a::B {}", "(struct a::B)"),
+    ("if a { b }", "(if a {b})"),
+    ("while x == (S { a: 1 }) { }", "(while (== x (struct S (a 1))) {})"),
+    ("if f(S { a: 1 }) { S { a: 2 } }", "(if (call f (struct S (a 1))) {(struct S (a 2))})"),
+    ("switch s { S { a: 1 } => 0 }", "(switch s ((struct S (a 1)) 0))"),
+  ];
+
+  for (source, tree) in cases {
+    assert_eq!(parse_expression(source), tree, "{source:?}");
+  };
+}
+
+#[test]
+fn precedence_and_associativity() {
+  let cases = [
+    ("-a ** 2", "(- (** a 2))"),
+    ("a ** b ** c", "(** a (** b c))"),
+    ("a ** -b", "(** a (- b))"),
+    ("a = b += c", "(= a (+= b c))"),
+    ("a - b - c", "(- (- a b) c)"),
+    ("-a * b", "(* (- a) b)"),
+    ("&mut buf", "(&mut buf)"),
+    ("&&x", "(& (& x))"),
+    ("&a.b", "(& (. a b))"),
+    ("!a && ~b || c ^^ d", "(|| (&& (! a) (~ b)) (^^ c d))"),
+    ("x |> f($) |> g", "(|> (|> x (call f $)) g)"),
+    ("s |>= $.double()", "(|>= s (call (. $ double)))"),
+    ("a[i]++?", "(((index a i) ++) ?)"),
+    ("a << 1 + 2 < 3 == 4 | 5 ^ 6 & 7", "(== (< (<< a (+ 1 2)) 3) (| 4 (^ 5 (& 6 7))))"),
+    ("a % b >>> c", "(>>> (% a b) c)"),
+    ("(a + b) * c", "(* (+ a b) c)"),
+    ("io::stdin().read_line(&mut buf)", "(call (. (call io::stdin) read_line) (&mut buf))"),
+    ("(str.len)()", "(call (. str len))"),
+    ("f(a, b,)", "(call f a b)"),
+  ];
+
+  for (source, tree) in cases {
+    assert_eq!(parse_expression(source), tree, "{source:?}");
+  };
+}
+
+#[test]
+fn every_operator_parses() {
+  for operator in enum_iterator::all::<Operator>() {
+    let text = operator.to_str();
+
+    let (source, tree) = match operator {
+      Operator::BitNot | Operator::LogicalNot => (format!("{text}a"), format!("({text} a)")),
+      Operator::Increment | Operator::Decrement | Operator::Try => (format!("a{text}"), format!("(a {text})")),
+      Operator::Dot => ("a.b".to_owned(), "(. a b)".to_owned()),
+      _ => {
+        assert!(operator.infix().is_some(), "{operator:?} has no precedence");
+
+        (format!("a {text} b"), format!("({text} a b)"))
+      },
+    };
+
+    assert_eq!(parse_expression(&source), tree, "{source:?}");
+  };
+}
+
+#[test]
+fn every_operand_start_parses() {
+  let samples = ["a", "1", "\"s\"", "'c'", "b'c'", "$", "drop!", "__llvm {}"].map(str::to_owned);
+
+  let texts = enum_iterator::all::<Operator>().map(Operator::to_str)
+    .chain(enum_iterator::all::<Punctuation>().map(Punctuation::to_str))
+    .chain(enum_iterator::all::<Grouping>().map(Grouping::to_str))
+    .chain(enum_iterator::all::<Keyword>().map(Keyword::to_str))
+    .map(str::to_owned)
+    .chain(samples);
+
+  let mut accepted = 0;
+
+  for text in texts {
+    let mut source_map = SourceMap::new();
+    let file = source_map.add("operand.zy".into(), format!("{text} x"));
+
+    let mut interner = Interner::default();
+    let tokens = tokenizer::tokenize_file(&source_map, file, &mut interner).unwrap();
+    let mut asterizer = Asterizer::from_tokens(tokens, interner);
+
+    if !asterizer.starts_operand() {
+      continue;
+    };
+
+    accepted += 1;
+
+    // whatever goes wrong later on, the first token must have been read
+    if let Err(err) = asterizer.expression() {
+      assert_ne!(err.span().unwrap().start, 0, "{text:?} starts an operand but can't be parsed as one: {err}");
+    };
+  };
+
+  assert!(accepted > 0);
+}
+
+#[test]
+fn types() {
+  let cases = [
+    "&[]u8",
+    "&[5]u8",
+    "&mut str",
+    "&[]&c_str",
+    "&&T",
+    "&&mut T",
+    "Something<i32>",
+    "A<B<C>>",
+    "A<B<C<D>>>",
+    "[]A<B, C>",
+    "&super::str",
+    "super::super::x",
+    "std::ops::Multiply",
+    "This",
+    "void",
+  ];
+
+  for source in cases {
+    let parsed = parse(&format!("f: x: {source} {{}};"));
+
+    let ty = &parsed.function(0).parameters[0].ty;
+
+    assert_eq!(parsed.dump_type(ty), source);
+    assert_eq!(&parsed.source_map.source(ty.span.file)[ty.span.start..ty.span.end], source);
+  };
+
+  let parsed = parse("f: x: &&mut T {};");
+  let TypeKind::Reference { mutable: false, inner } = &parsed.function(0).parameters[0].ty.kind else {
+    panic!("expected a reference");
+  };
+
+  assert_eq!(&parsed.source_map.source(inner.span.file)[inner.span.start..inner.span.end], "&mut T");
+}
+
+#[test]
+fn bare_type_snippet() {
+  let mut source_map = SourceMap::new();
+  let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("snippets/type_make.zy");
+  let file = source_map.load(&path).unwrap();
+
+  let mut interner = Interner::default();
+  let tokens = tokenizer::tokenize_file(&source_map, file, &mut interner).unwrap();
+
+  let mut asterizer = Asterizer::from_tokens(tokens, interner);
+  let ty = asterizer.ty().unwrap();
+
+  assert!(asterizer.peek().is_none());
+
+  let parsed = Parsed {
+    source_map,
+    interner: asterizer.interner,
+    ast: Ast { declarations: vec![] },
+    errors: vec![],
+  };
+
+  assert_eq!(parsed.dump_type(&ty), "&[5]u8");
+}
+
+#[test]
+fn generics_in_expressions() {
+  let cases = [
+    ("a < b", "(< a b)"),
+    ("a < b > c", "(> (< a b) c)"),
+    ("a < b > -c", "(> (< a b) (- c))"),
+    ("f<i32>(x)", "(call f<i32> x)"),
+    ("x = A<B<C>>", "(= x A<B<C>>)"),
+    ("ctr < text.len()", "(< ctr (call (. text len)))"),
+    ("a < b && c > d", "(&& (< a b) (> c d))"),
+    ("g(a < b, c > d)", "(call g (< a b) (> c d))"),
+    ("Vec<i32>.len", "(. Vec<i32> len)"),
+    ("a >> b", "(>> a b)"),
+    ("a < b >> c", "(< a (>> b c))"),
+    ("super::x", "super::x"),
+  ];
+
+  for (source, tree) in cases {
+    assert_eq!(parse_expression(source), tree, "{source:?}");
+  };
+}
@@ -0,0 +1,16 @@
+use crate::compiler::{
+  Interner,
+  SourceMap,
+};
+use crate::tokenizer;
+
+/// Tokenizes `source` as a file of its own, returning how many tokens it was
+/// split into, or `None` if it has any errors.
+pub fn tokenize(source: String) -> Option<usize> {
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("bench.zy".into(), source);
+
+  tokenizer::tokenize_file(&source_map, file, &mut Interner::default())
+    .ok()
+    .map(|tokens| tokens.len())
+}
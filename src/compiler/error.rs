@@ -3,6 +3,9 @@ use snafu::prelude::*;
 use std::path::PathBuf;
 
 use crate::arg_parser::error::ArgumentError;
+use crate::asterizer::error::AsterError;
+use crate::compiler::SourceLocation;
+use crate::tokenizer::Span;
 use crate::tokenizer::error::TokenError;
 
 #[derive(Debug, Snafu)]
@@ -17,11 +20,56 @@ pub(crate) enum CompilerError {
   #[snafu(display("path is a directory: {}", path.to_string_lossy()))]
   PathIsDirectory { path: PathBuf },
 
+  #[snafu(display("invalid UTF-8 in {} at byte {offset}", path.to_string_lossy()))]
+  InvalidUtf8 { path: PathBuf, offset: usize },
+
   #[snafu(display("{err}"))]
   Argument { err: ArgumentError },
 
-  #[snafu(display("Token error: {err:?}"))]
+  #[snafu(display("Token error: {err}"))]
   Token { err: TokenError },
+
+  #[snafu(display("Syntax error: {err}"))]
+  Aster { err: AsterError },
+
+  /// A lint finding, which is reported as a warning rather than an error.
+  #[snafu(display("{err}"))]
+  Lint { err: TokenError },
+
+  #[snafu(display("aborting due to {} previous errors", errs.len()))]
+  Multiple { errs: Vec<CompilerError> },
+
+  #[snafu(display("{err}\n--> {}:{source_location}\n{line}", path.to_string_lossy()))]
+  At {
+    err: Box<CompilerError>,
+    path: PathBuf,
+    source_location: SourceLocation,
+    line: String,
+  },
+}
+
+impl CompilerError {
+  pub(crate) fn span(&self) -> Option<Span> {
+    match self {
+      | CompilerError::Token { err }
+      | CompilerError::Lint { err } => Some(err.span()),
+      CompilerError::Aster { err } => Some(err.span()),
+      _ => None,
+    }
+  }
+}
+
+impl From<Vec<CompilerError>> for CompilerError {
+  /// Collapses a list of errors, which must not be empty, into one.
+  fn from(mut errs: Vec<CompilerError>) -> Self {
+    assert!(!errs.is_empty(), "no errors to report");
+
+    if errs.len() == 1 {
+      return errs.remove(0);
+    };
+
+    Self::Multiple { errs }
+  }
 }
 
 impl From<TokenError> for CompilerError {
@@ -30,6 +78,12 @@ impl From<TokenError> for CompilerError {
   }
 }
 
+impl From<AsterError> for CompilerError {
+  fn from(err: AsterError) -> Self {
+    Self::Aster { err }
+  }
+}
+
 impl From<ArgumentError> for CompilerError {
   fn from(err: ArgumentError) -> Self {
     Self::Argument { err }
@@ -40,6 +94,7 @@ impl crate::LazyHelp for CompilerError {
   fn should_print_message(&self) -> bool {
     match self {
       CompilerError::Argument { err } => err.should_print_message(),
+      CompilerError::At { err, .. } => err.should_print_message(),
       _ => true,
     }
   }
@@ -47,6 +102,7 @@ impl crate::LazyHelp for CompilerError {
   fn should_print_help_text(&self) -> bool {
     match self {
       CompilerError::Argument { err } => err.should_print_help_text(),
+      CompilerError::At { err, .. } => err.should_print_help_text(),
       _ => false,
     }
   }
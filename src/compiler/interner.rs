@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// An interned string, which is cheap to copy and compare.  Its text can be
+/// looked up with [`Interner::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Symbol(u32);
+
+#[derive(Default)]
+pub(crate) struct Interner {
+  symbols: HashMap<Rc<str>, Symbol>,
+  strings: Vec<Rc<str>>,
+}
+
+#[allow(unused)]
+impl Interner {
+  pub(crate) fn intern(&mut self, text: &str) -> Symbol {
+    if let Some(symbol) = self.symbols.get(text) {
+      return *symbol;
+    };
+
+    let symbol = Symbol(self.strings.len() as u32);
+    let text: Rc<str> = text.into();
+
+    self.strings.push(text.clone());
+    self.symbols.insert(text, symbol);
+
+    symbol
+  }
+
+  pub(crate) fn resolve(&self, symbol: Symbol) -> &str {
+    &self.strings[symbol.0 as usize]
+  }
+}
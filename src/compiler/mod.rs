@@ -1,8 +1,15 @@
 mod module;
 mod traits;
+mod source_map;
+mod interner;
 pub(crate) mod error;
 
+#[cfg(test)]
+mod tests;
+
 pub(crate) use module::CompilerModule;
+pub(crate) use source_map::*;
+pub(crate) use interner::*;
 pub(crate) use traits::*;
 
 use crate::{Result, ok};
@@ -37,11 +44,14 @@ pub(super) struct CompilerSettings {
   pub(super) output_file: PathBuf,
   pub(super) llc: PathBuf,
   pub(super) cc: PathBuf,
+  pub(super) mixed_script_lint: bool,
 }
 
 pub(super) struct Compiler<W: CompilerWorkflow> {
   pub(crate) settings: CompilerSettings,
   pub(crate) store: CompilerStore<W>,
+  pub(crate) source_map: SourceMap,
+  pub(crate) interner: Interner,
 }
 
 impl<W: CompilerWorkflow> CompilerJob<W> {
@@ -142,6 +152,7 @@ impl<W: CompilerWorkflow> CompilerStore<W> {
 
     self.add_module(CompilerModule {
       path: module.path.to_owned(),
+      file: module.file,
       data: CompilerJob::Taken,
     })
   }
@@ -177,6 +188,8 @@ impl<W: CompilerWorkflow> Compiler<W> {
     Self {
       settings,
       store: CompilerStore::new(),
+      source_map: SourceMap::new(),
+      interner: Interner::default(),
     }
   }
 
@@ -243,7 +256,7 @@ impl<W: CompilerWorkflow> Compiler<W> {
   }
 
   pub(crate) fn compile(&mut self) -> Result<<W::Generator as Generate<W>>::Out> {
-    let module: CompilerModule<W> = self.settings.input_file.as_path().try_into()?;
+    let module = CompilerModule::<W>::open(&self.settings.input_file, &mut self.source_map)?;
     let handle = self.store.store_module(module);
 
     self.bring_to_stage(&handle, CompilationStage::Done)?;
@@ -7,11 +7,14 @@ use crate::Result;
 use crate::compiler::{
   CompilerJob,
   CompilerWorkflow,
+  FileId,
+  SourceMap,
   error::*,
 };
 
 pub(crate) struct CompilerModule<W: CompilerWorkflow> {
   pub(crate) path: PathBuf,
+  pub(crate) file: FileId,
   pub(crate) data: CompilerJob<W>,
 }
 
@@ -21,10 +24,8 @@ impl<W: CompilerWorkflow> CompilerModule<W> {
   }
 }
 
-impl<W: CompilerWorkflow> TryFrom<&Path> for CompilerModule<W> {
-  type Error = CompilerError;
-
-  fn try_from(path: &Path) -> Result<Self> {
+impl<W: CompilerWorkflow> CompilerModule<W> {
+  pub(crate) fn open(path: &Path, source_map: &mut SourceMap) -> Result<Self> {
     if !path.exists() {
       return PathNotExistsSnafu { path }.fail();
     };
@@ -36,12 +37,13 @@ impl<W: CompilerWorkflow> TryFrom<&Path> for CompilerModule<W> {
         return PathIsDirectorySnafu { path }.fail();
       };
 
-      return path.as_path().try_into();
+      return Self::open(&path, source_map);
     }
 
     Ok(Self {
       data: CompilerJob::Unprocessed,
       path: path.to_path_buf(),
+      file: source_map.load(path)?,
     })
   }
 }
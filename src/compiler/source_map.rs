@@ -0,0 +1,175 @@
+use std::fmt::Display;
+use std::path::{
+  Path,
+  PathBuf,
+};
+
+use crate::Result;
+use crate::compiler::error::*;
+use crate::tokenizer::Span;
+
+/// Identifies a file that has been loaded into the [`SourceMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct FileId(usize);
+
+pub(crate) struct SourceFile {
+  pub(crate) path: PathBuf,
+  pub(crate) source: String,
+  /// Byte offset of the first character of every line.
+  line_starts: Vec<usize>,
+  /// Length of the byte order mark that was stripped from the start of the
+  /// file, which locations add back to give offsets into the file itself.
+  bom_length: usize,
+}
+
+/// A resolved position in a source file.  `line` and `column` are 1-based
+/// and `column` is counted in characters, while `offset` is the 0-based byte
+/// offset into the file as it is on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SourceLocation {
+  pub(crate) line: usize,
+  pub(crate) column: usize,
+  pub(crate) offset: usize,
+}
+
+pub(crate) struct SourceMap {
+  files: Vec<SourceFile>,
+}
+
+impl Display for SourceLocation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}:{}", self.line, self.column)
+  }
+}
+
+impl SourceFile {
+  fn new(path: PathBuf, mut source: String) -> Self {
+    // a byte order mark is meaningless in UTF-8, so spans start after it
+    let bom_length = if source.starts_with('\u{feff}') {
+      '\u{feff}'.len_utf8()
+    } else {
+      0
+    };
+
+    source.drain(..bom_length);
+
+    // `\r\n` ends a line at its `\n`, so it only counts once
+    let line_starts = std::iter::once(0)
+      .chain(source.match_indices('\n').map(|(offset, _)| offset + 1))
+      .collect();
+
+    Self {
+      path,
+      source,
+      line_starts,
+      bom_length,
+    }
+  }
+
+  pub(crate) fn location(&self, offset: usize) -> SourceLocation {
+    let offset = offset.min(self.source.len());
+    let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+    let column = self.source[self.line_starts[line]..offset].chars().count();
+
+    SourceLocation {
+      line: line + 1,
+      column: column + 1,
+      offset: offset + self.bom_length,
+    }
+  }
+
+  /// Returns the text of a 1-based line, without its line ending.
+  pub(crate) fn line_text(&self, line: usize) -> Option<&str> {
+    let start = *self.line_starts.get(line.checked_sub(1)?)?;
+    let end = self.line_starts.get(line)
+      .copied()
+      .unwrap_or(self.source.len());
+
+    Some(self.source[start..end].trim_end_matches(['\n', '\r']))
+  }
+}
+
+impl SourceMap {
+  pub(crate) fn new() -> Self {
+    Self {
+      files: vec![],
+    }
+  }
+
+  pub(crate) fn add(&mut self, path: PathBuf, source: String) -> FileId {
+    let file = FileId(self.files.len());
+
+    self.files.push(SourceFile::new(path, source));
+
+    file
+  }
+
+  /// Reads a file into the map, or returns the existing id if it has already
+  /// been loaded.
+  pub(crate) fn load(&mut self, path: &Path) -> Result<FileId> {
+    if let Some(index) = self.files.iter().position(|file| file.path == path) {
+      return Ok(FileId(index));
+    };
+
+    let bytes = match std::fs::read(path) {
+      Ok(x) => x,
+      Err(err) => return IOSnafu { err: err.to_string() }.fail(),
+    };
+
+    let source = match String::from_utf8(bytes) {
+      Ok(x) => x,
+      Err(err) => return InvalidUtf8Snafu {
+        path,
+        offset: err.utf8_error().valid_up_to(),
+      }.fail(),
+    };
+
+    Ok(self.add(path.to_path_buf(), source))
+  }
+
+  pub(crate) fn file(&self, file: FileId) -> &SourceFile {
+    &self.files[file.0]
+  }
+
+  pub(crate) fn source(&self, file: FileId) -> &str {
+    &self.file(file).source
+  }
+
+  /// Resolves the start and end of a span.
+  pub(crate) fn locate(&self, span: &Span) -> (SourceLocation, SourceLocation) {
+    let file = self.file(span.file);
+
+    (file.location(span.start), file.location(span.end))
+  }
+
+  /// Returns the text of the line that a span starts on.
+  pub(crate) fn line_text(&self, span: &Span) -> &str {
+    let (start, _) = self.locate(span);
+
+    self.file(span.file)
+      .line_text(start.line)
+      .unwrap_or_default()
+  }
+
+  /// Attaches the location of an error to it, if it has one.
+  pub(crate) fn annotate(&self, err: CompilerError) -> CompilerError {
+    if let CompilerError::Multiple { errs } = err {
+      return CompilerError::Multiple {
+        errs: errs.into_iter().map(|err| self.annotate(err)).collect(),
+      };
+    };
+
+    let Some(span) = err.span() else {
+      return err;
+    };
+
+    let (source_location, _) = self.locate(&span);
+
+    CompilerError::At {
+      path: self.file(span.file).path.to_owned(),
+      line: self.line_text(&span).to_owned(),
+      source_location,
+      err: Box::new(err),
+    }
+  }
+}
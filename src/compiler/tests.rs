@@ -0,0 +1,77 @@
+use crate::compiler::{
+  FileId,
+  SourceMap,
+  error::CompilerError,
+};
+use crate::tokenizer::Span;
+
+#[test]
+fn locate_spans() {
+  let mut source_map = SourceMap::new();
+  let first = source_map.add("first.zy".into(), "a := 1;\nb := 2;\n".into());
+  let second = source_map.add("second.zy".into(), "s := \"é✅\";\nλ := s |> len".into());
+
+  let source = source_map.source(second);
+  let start = source.find("len").unwrap();
+  let span = Span { file: second, start, end: start + "len".len() };
+
+  let (start, end) = source_map.locate(&span);
+
+  // `λ` is two bytes but one column
+  assert_eq!((start.line, start.column, start.offset), (2, 11, 25));
+  assert_eq!((end.line, end.column, end.offset), (2, 14, 28));
+  assert_eq!(source_map.line_text(&span), "λ := s |> len");
+
+  let source = source_map.source(second);
+  let start = source.find('"').unwrap() + 1;
+  let span = Span { file: second, start, end: start + "é✅".len() };
+
+  let (start, end) = source_map.locate(&span);
+
+  assert_eq!((start.line, start.column), (1, 7));
+  assert_eq!((end.line, end.column), (1, 9));
+  assert_eq!(source_map.line_text(&span), "s := \"é✅\";");
+
+  // the same offsets mean something else in another file
+  let span = Span { file: first, start: 8, end: 9 };
+  let (start, _) = source_map.locate(&span);
+
+  assert_eq!((start.line, start.column), (2, 1));
+  assert_eq!(source_map.line_text(&span), "b := 2;");
+}
+
+/// Writes `bytes` to a file of its own and loads it.
+fn load_bytes(name: &str, bytes: &[u8]) -> (SourceMap, crate::Result<FileId>) {
+  let path = std::env::temp_dir().join(format!("lazy-{name}-{}.zy", std::process::id()));
+  std::fs::write(&path, bytes).unwrap();
+
+  let mut source_map = SourceMap::new();
+  let result = source_map.load(&path);
+  std::fs::remove_file(&path).unwrap();
+
+  (source_map, result)
+}
+
+#[test]
+fn offsets_after_bom() {
+  let (source_map, file) = load_bytes("bom", "\u{feff}a := 1;\nb := @;".as_bytes());
+  let file = file.unwrap();
+
+  let source = source_map.source(file);
+  let start = source.find('@').unwrap();
+
+  // spans don't include the byte order mark, but locations do
+  assert_eq!(start, 13);
+
+  let (location, _) = source_map.locate(&Span { file, start, end: start + 1 });
+
+  assert_eq!((location.line, location.column, location.offset), (2, 6, 16));
+
+  let (_, result) = load_bytes("bom-invalid-utf8", b"\xef\xbb\xbfa := \"\xff\";");
+
+  match result {
+    Err(CompilerError::InvalidUtf8 { offset, .. }) => assert_eq!(offset, 9),
+    Err(err) => panic!("unexpected error {err}"),
+    Ok(_) => panic!("invalid UTF-8 was accepted"),
+  };
+}
@@ -10,6 +10,9 @@ pub(super) fn print_help_text() {
       -i, --input=<FILE>                     Sets the program's entry file\n  \
       -o, --output=<FILE>                    Sets the program's output file\n  \
     \n\
+    Lints:\n  \
+      --lint-mixed-script                    Warns about confusable or mixed-script names\n  \
+    \n\
     Tooling:\n  \
       --llc=<FILE>                           Path to the llc executable\n  \
       --cc=<FILE>                            Path to the cc executable\n  \
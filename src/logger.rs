@@ -1,5 +1,9 @@
 use colored::Colorize;
 
+/// The log target for diagnostics about the code being compiled, like lint
+/// findings, which are shown even when other warnings are not.
+pub(crate) const DIAGNOSTICS: &str = "diagnostics";
+
 struct Logger;
 
 impl colog::format::CologStyle for Logger {
@@ -40,6 +44,7 @@ pub(super) fn init() {
   let mut builder = colog::basic_builder();
 
   builder.filter_level(log::LevelFilter::Error);
+  builder.filter_module(DIAGNOSTICS, log::LevelFilter::Warn);
   builder.parse_default_env();
 
   builder.format(colog::formatter(Logger));
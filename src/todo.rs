@@ -1,28 +1,14 @@
 use crate::Result;
 use crate::compiler::*;
-use crate::tokenizer::Token;
+use crate::asterizer::Ast;
 
-pub(super) struct Asterizer;
 pub(super) struct Translator;
 pub(super) struct Checker;
 pub(super) struct Generator;
 pub(super) struct Outputter;
 
-impl<W: CompilerWorkflow> Asterize<W> for Asterizer {
-  type In = Vec<Token>;
-  type Out = ();
-
-  fn new() -> Self {
-    todo!()
-  }
-
-  fn asterize(self, _compiler: &mut Compiler<W>, _: Self::In) -> Result<Self::Out> {
-    todo!()
-  }
-}
-
 impl<W: CompilerWorkflow> Translate<W> for Translator {
-  type In = ();
+  type In = Ast;
   type Out = ();
 
   fn new() -> Self {
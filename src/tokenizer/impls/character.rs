@@ -0,0 +1,100 @@
+use crate::{Result, ok};
+use crate::tokenizer::{
+  PeekReader,
+  Tokenizer,
+  TokenKind,
+  SpanStart,
+  error::*,
+};
+
+impl Tokenizer {
+  /// Reads a character literal, or a byte character literal including its `b`
+  /// prefix if `byte` is set.
+  pub(in crate::tokenizer) fn character(&mut self, reader: &mut PeekReader, byte: bool) -> Result {
+    trace!("Tokenizer::character");
+
+    let what = if byte { What::ByteChar } else { What::Char };
+    let start = reader.span_start();
+
+    if byte {
+      reader.seek();
+    };
+
+    let Some(char_quote!()) = reader.next() else {
+      return ExpectedSnafu { what, span: reader.span_start().into_span(reader.position) }.fail()?;
+    };
+
+    let mut kind = None;
+    let mut count = 0;
+
+    // like strings, errors inside of the literal don't stop it from being read
+    // up to its closing quote
+    let mut failed = false;
+
+    loop {
+      let position = reader.position;
+
+      let Some(ch) = reader.next() else {
+        return UnterminatedSnafu { what, span: start.into_span(reader.position) }.fail()?;
+      };
+
+      let item_start = reader.span_start_at(position);
+
+      let result = match ch {
+        char_quote!() => break,
+        '\n' => return UnterminatedSnafu { what, span: start.into_span(position) }.fail()?,
+        '\\' => self.character_escape(reader, item_start, byte),
+        ch if byte => {
+          if ch.is_ascii() {
+            Ok(TokenKind::ByteChar(ch as u8))
+          } else {
+            OutOfRangeSnafu { what, span: item_start.into_span(reader.position) }.fail().map_err(Into::into)
+          }
+        },
+        ch => Ok(TokenKind::Char(ch)),
+      };
+
+      match result {
+        Ok(result) => kind = Some(result),
+        Err(err) => {
+          self.errors.push(err);
+          failed = true;
+        },
+      };
+
+      count += 1;
+    };
+
+    let span = start.into_span(reader.position);
+
+    if count == 0 {
+      return EmptySnafu { what, span }.fail()?;
+    };
+
+    if count > 1 {
+      return MultipleCharactersSnafu { what, span }.fail()?;
+    };
+
+    let kind = match kind {
+      Some(kind) if !failed => kind,
+      _ => TokenKind::Error,
+    };
+
+    self.push_tok(kind, start, reader.position);
+
+    ok
+  }
+
+  fn character_escape(&mut self, reader: &mut PeekReader, escape_start: SpanStart, byte: bool) -> Result<TokenKind> {
+    let escape = self.escape(reader, escape_start)?;
+    let span = escape_start.into_span(reader.position);
+
+    Ok({
+      if byte {
+        TokenKind::ByteChar(escape.into_byte(span)?)
+      } else {
+        TokenKind::Char(escape.into_char(span)?)
+      }
+    })
+  }
+}
@@ -1,29 +1,98 @@
-use crate::ok;
-
-use crate::Result;
+use crate::{Result, ok};
 use crate::tokenizer::{
   PeekReader,
   Tokenizer,
   TokenKind,
+  SpanStart,
+  error::*,
 };
 
+fn comment_kind(doc: bool) -> TokenKind {
+  if doc {
+    TokenKind::DocComment
+  } else {
+    TokenKind::Comment
+  }
+}
+
 impl Tokenizer {
-  pub(in crate::tokenizer) fn line_comment(&mut self, reader: &mut PeekReader) -> Result {
-    trace!("Tokenizer::line_comment");
-    let mut message = String::new();
+  /// Reads a `#!` line at the very start of a file, so that files can be run
+  /// as scripts.  It is kept as a plain comment.
+  pub(in crate::tokenizer) fn shebang(&mut self, reader: &mut PeekReader) -> Result {
+    trace!("Tokenizer::shebang");
+
     let start = reader.span_start();
 
-    for item in &mut *reader {
-      let item = item?;
+    let (Some('#'), Some('!')) = (reader.peek(), reader.peek_nth(1)) else {
+      return ok;
+    };
 
-      if let '\n' = item.ch {
-        break;
-      };
+    reader.seek();
+    reader.seek();
+
+    reader.seek_while(|ch| ch != '\n');
+
+    self.push_tok(TokenKind::Comment, start, reader.position);
+
+    ok
+  }
+
+  /// Reads a line comment.  `start` points at the first slash, and both
+  /// slashes must already have been consumed.
+  pub(in crate::tokenizer) fn line_comment(&mut self, reader: &mut PeekReader, start: SpanStart) -> Result {
+    trace!("Tokenizer::line_comment");
 
-      message.push(item.ch);
+    // `///` is a doc comment, but `////` is not
+    let doc = reader.peek() == Some('/') && reader.peek_nth(1) != Some('/');
+
+    if doc {
+      reader.seek();
+    };
+
+    reader.seek_while(|ch| ch != '\n');
+
+    self.push_tok(comment_kind(doc), start, reader.position);
+
+    ok
+  }
+
+  /// Reads a block comment, which may contain other block comments.  `start`
+  /// points at the opening slash, and the opening `/*` must already have been
+  /// consumed.
+  pub(in crate::tokenizer) fn block_comment(&mut self, reader: &mut PeekReader, start: SpanStart) -> Result {
+    trace!("Tokenizer::block_comment");
+
+    // `/** */` is a doc comment, but `/**/` and `/*** */` are not
+    let doc = reader.peek() == Some('*') && !matches!(reader.peek_nth(1), Some('*' | '/'));
+
+    if doc {
+      reader.seek();
+    };
+
+    let mut depth = 1usize;
+
+    loop {
+      match (reader.next(), reader.peek()) {
+        (None, _) => {
+          return UnterminatedSnafu { what: What::BlockComment, span: start.into_span(reader.position) }.fail()?;
+        },
+        (Some('/'), Some('*')) => {
+          reader.seek();
+          depth += 1;
+        },
+        (Some('*'), Some('/')) => {
+          reader.seek();
+          depth -= 1;
+
+          if depth == 0 {
+            break;
+          };
+        },
+        _ => {},
+      };
     };
 
-    self.push_tok(TokenKind::Comment(message.trim().into()), start, reader.position);
+    self.push_tok(comment_kind(doc), start, reader.position);
 
     ok
   }
@@ -0,0 +1,121 @@
+use crate::Result;
+use crate::tokenizer::{
+  PeekReader,
+  Tokenizer,
+  Span,
+  SpanStart,
+  error::*,
+};
+
+/// A decoded escape sequence, before it has been checked against the kind of
+/// literal that it appears in.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Escape {
+  Byte(u8),
+  Char(char),
+}
+
+impl Escape {
+  /// Resolves this escape in the context of a Unicode literal, where `\x`
+  /// escapes are restricted to ASCII.
+  pub(crate) fn into_char(self, span: Span) -> Result<char> {
+    match self {
+      Escape::Byte(byte @ 0..=0x7f) => Ok(byte as char),
+      Escape::Byte(_) => OutOfRangeSnafu { what: What::HexEscape, span }.fail()?,
+      Escape::Char(ch) => Ok(ch),
+    }
+  }
+
+  /// Resolves this escape in the context of a byte literal, where `\u{...}`
+  /// escapes are not allowed.
+  pub(crate) fn into_byte(self, span: Span) -> Result<u8> {
+    match self {
+      Escape::Byte(byte) => Ok(byte),
+      Escape::Char(_) => UnexpectedSnafu { what: What::UnicodeEscape, span }.fail()?,
+    }
+  }
+}
+
+impl Tokenizer {
+  fn hex_digit(reader: &mut PeekReader, what: What, start: SpanStart) -> Result<u32> {
+    let Some(digit) = reader.peek().and_then(|ch| ch.to_digit(16)) else {
+      return MalformedEscapeSnafu { what, span: start.into_span(reader.position) }.fail()?;
+    };
+
+    reader.seek();
+
+    Ok(digit)
+  }
+
+  /// Reads an escape sequence.  `start` points at the backslash, which must
+  /// already have been consumed.
+  pub(in crate::tokenizer) fn escape(&mut self, reader: &mut PeekReader, start: SpanStart) -> Result<Escape> {
+    trace!("Tokenizer::escape");
+
+    let Some(ch) = reader.next() else {
+      return MalformedEscapeSnafu { what: What::Escape, span: start.into_span(reader.position) }.fail()?;
+    };
+
+    Ok(match ch {
+      'n' => Escape::Byte(b'\n'),
+      'r' => Escape::Byte(b'\r'),
+      't' => Escape::Byte(b'\t'),
+      '0' => Escape::Byte(b'\0'),
+      '\\' => Escape::Byte(b'\\'),
+      '"' => Escape::Byte(b'"'),
+      '\'' => Escape::Byte(b'\''),
+      'x' => {
+        let high = Self::hex_digit(reader, What::HexEscape, start)?;
+        let low = Self::hex_digit(reader, What::HexEscape, start)?;
+
+        Escape::Byte((high << 4 | low) as u8)
+      },
+      'u' => {
+        let malformed = |reader: &PeekReader| MalformedEscapeSnafu {
+          what: What::UnicodeEscape,
+          span: start.into_span(reader.position),
+        };
+
+        if reader.peek() != Some('{') {
+          return malformed(reader).fail()?;
+        };
+        reader.seek();
+
+        let mut value = 0u32;
+        let mut digits = 0;
+
+        loop {
+          let Some(ch) = reader.peek() else {
+            return malformed(reader).fail()?;
+          };
+
+          if ch == '}' {
+            reader.seek();
+            break;
+          };
+
+          if digits == 6 {
+            return malformed(reader).fail()?;
+          };
+
+          value = value << 4 | Self::hex_digit(reader, What::UnicodeEscape, start)?;
+          digits += 1;
+        };
+
+        if digits == 0 {
+          return malformed(reader).fail()?;
+        };
+
+        let Some(ch) = char::from_u32(value) else {
+          return OutOfRangeSnafu {
+            what: What::UnicodeEscape,
+            span: start.into_span(reader.position),
+          }.fail()?;
+        };
+
+        Escape::Char(ch)
+      },
+      ch => return UnknownEscapeSnafu { ch, span: start.into_span(reader.position) }.fail()?,
+    })
+  }
+}
@@ -1,51 +1,110 @@
+use std::borrow::Cow;
+
+use unicode_normalization::{
+  IsNormalized,
+  UnicodeNormalization,
+  is_nfc_quick,
+};
+use unicode_security::{
+  MixedScript,
+  skeleton,
+};
+
 use crate::{Result, ok};
+use crate::compiler::error::CompilerError;
 use crate::tokenizer::{
   PeekReader,
+  Span,
   Tokenizer,
   TokenKind,
   Keyword,
-  SpanStart,
   error::*,
 };
+use crate::tokenizer::impls::llvm::INLINE_LLVM;
+
+/// Whether `ch` can start an identifier, which follows UAX #31 with `_`
+/// added.  ASCII is checked first since it is by far the most common.
+pub(in crate::tokenizer) fn is_ident_start(ch: char) -> bool {
+  matches!(ch, ident!()) || (!ch.is_ascii() && unicode_ident::is_xid_start(ch))
+}
+
+/// Whether `ch` can continue an identifier.
+pub(in crate::tokenizer) fn is_ident_continue(ch: char) -> bool {
+  matches!(ch, ident!() | decimal!()) || (!ch.is_ascii() && unicode_ident::is_xid_continue(ch))
+}
 
 impl Tokenizer {
+  /// Lints a name for the confusables from UAX #39: a name whose characters
+  /// come from different scripts, or a name that has the same skeleton as an
+  /// earlier one, as long as one of the two isn't plain ASCII.
+  fn lint_identifier(&mut self, name: &str, span: Span) {
+    if !name.is_ascii() && !name.is_single_script() {
+      self.warnings.push(CompilerError::Lint {
+        err: MixedScriptSnafu { name, span }.build(),
+      });
+    };
+
+    let skeleton = skeleton(name).collect::<String>();
+
+    match self.skeletons.get(&skeleton) {
+      Some(other) if other != name && !(other.is_ascii() && name.is_ascii()) => {
+        self.warnings.push(CompilerError::Lint {
+          err: ConfusableSnafu { name, other, span }.build(),
+        });
+      },
+      Some(_) => {},
+      None => {
+        self.skeletons.insert(skeleton, name.to_owned());
+      },
+    };
+  }
+
   pub(in crate::tokenizer) fn identifier(&mut self, reader: &mut PeekReader) -> Result {
     trace!("Tokenizer::identifier");
 
-    let Some(item) = reader.next() else {
-      return ExpectedSnafu { what: What::Identifier }.fail()?;
-    };
-    let item = item?;
+    let start = reader.span_start();
 
-    let ident!() = item.ch else {
-      return ExpectedSnafu { what: What::Identifier }.fail()?;
+    if !reader.peek().is_some_and(is_ident_start) {
+      return ExpectedSnafu { what: What::Identifier, span: start.into_span(reader.position) }.fail()?;
     };
 
-    let start = SpanStart(item.position);
-    let mut name = String::from(item.ch);
+    reader.seek_while(is_ident_continue);
 
-    loop {
-      let Some(peek) = reader.peek()? else {
-        break;
-      };
+    let name = reader.slice(start.position, reader.position);
 
-      let (ident!() | decimal!()) = peek.ch else {
-        break;
-      };
+    if name == INLINE_LLVM {
+      return self.inline_llvm(reader, start);
+    };
 
-      name.push(peek.ch);
-      reader.seek();
+    // names that look the same should be the same, however they were encoded
+    let name = match is_nfc_quick(name.chars()) {
+      IsNormalized::Yes => Cow::Borrowed(name),
+      _ => Cow::Owned(name.nfc().collect::<String>()),
     };
 
-    let kind = if let Some(keyword) = Keyword::from_str(&name) {
+    let keyword = Keyword::from_str(&name);
+
+    if self.mixed_script_lint && keyword.is_none() {
+      self.lint_identifier(&name, start.into_span(reader.position));
+    };
+
+    // `name!` invokes a macro, while `name !x` and `name != x` don't, and
+    // neither does a reserved keyword like in `while!done`
+    let is_macro = reader.peek() == Some('!')
+      && reader.peek_nth(1) != Some('=')
+      && keyword.is_none_or(Keyword::is_contextual);
+
+    let kind = if is_macro {
+      reader.seek();
+      TokenKind::MacroName(self.interner.intern(&name))
+    } else if let Some(keyword) = keyword {
       TokenKind::Keyword(keyword)
     } else {
-      TokenKind::Identifier(name)
+      TokenKind::Identifier(self.interner.intern(&name))
     };
 
     self.push_tok(kind, start, reader.position);
 
     ok
   }
-
 }
@@ -0,0 +1,69 @@
+use crate::{Result, ok};
+use crate::tokenizer::{
+  PeekReader,
+  Tokenizer,
+  TokenKind,
+  SpanStart,
+  error::*,
+};
+
+pub(in crate::tokenizer) const INLINE_LLVM: &str = "__llvm";
+
+impl Tokenizer {
+  /// Reads the body of an `__llvm { ... }` block as raw text.  `start` points
+  /// at the `__llvm` identifier, which must already have been consumed.  If it
+  /// isn't followed by a brace, it is just an identifier.
+  pub(in crate::tokenizer) fn inline_llvm(&mut self, reader: &mut PeekReader, start: SpanStart) -> Result {
+    trace!("Tokenizer::inline_llvm");
+
+    let mut lookahead = 0;
+
+    while let Some(whitespace!()) = reader.peek_nth(lookahead) {
+      lookahead += 1;
+    };
+
+    if reader.peek_nth(lookahead) != Some('{') {
+      let name = self.interner.intern(INLINE_LLVM);
+      self.push_tok(TokenKind::Identifier(name), start, reader.position);
+
+      return ok;
+    };
+
+    reader.seek_while(|ch| ch != '{');
+    reader.seek();
+
+    let mut content = String::new();
+    let mut depth = 1usize;
+
+    // braces inside of IR string constants and `;` comments don't count
+    let mut in_string = false;
+    let mut in_comment = false;
+
+    loop {
+      let Some(ch) = reader.next() else {
+        return UnterminatedSnafu { what: What::InlineLlvm, span: start.into_span(reader.position) }.fail()?;
+      };
+
+      match (in_string, in_comment, ch) {
+        (false, false, '{') => depth += 1,
+        (false, false, '}') => {
+          depth -= 1;
+
+          if depth == 0 {
+            break;
+          };
+        },
+        (false, false, ';') => in_comment = true,
+        (false, true, '\n') => in_comment = false,
+        (_, false, '"') => in_string = !in_string,
+        _ => {},
+      };
+
+      content.push(ch);
+    };
+
+    self.push_tok(TokenKind::InlineLlvm(content), start, reader.position);
+
+    ok
+  }
+}
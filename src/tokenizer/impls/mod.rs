@@ -3,35 +3,60 @@ pub(crate) mod comment;
 pub(crate) mod ident;
 pub(crate) mod operator;
 pub(crate) mod numeric;
+pub(crate) mod escape;
+pub(crate) mod string;
+pub(crate) mod character;
+pub(crate) mod llvm;
 
 use crate::{Result, ok};
 use crate::tokenizer::{
   PeekReader,
   TokenKind,
   Grouping,
+  error::*,
 };
+use string::StringPrefix;
 
 impl crate::tokenizer::Tokenizer {
   pub(in crate::tokenizer) fn base(&mut self, reader: &mut PeekReader) -> Result {
     trace!("Tokenizer::base");
     let start = reader.span_start();
 
-    let Some(item) = reader.peek()? else {
+    let Some(ch) = reader.peek() else {
       return ok;
     };
 
-    if let Some(grouping) = Grouping::from_str(&String::from(item.ch)) {
+    if let Some(grouping) = Grouping::from_str(ch.encode_utf8(&mut [0; 4])) {
       reader.seek();
       self.push_tok(TokenKind::Grouping(grouping), start, reader.position);
       return ok;
     };
 
-    match item.ch {
+    if let placeholder!() = ch {
+      reader.seek();
+      self.push_tok(TokenKind::PipePlaceholder, start, reader.position);
+      return ok;
+    };
+
+    match (ch, reader.peek_nth(1)) {
+      ('b', Some(string_quote!())) => return self.string(reader, StringPrefix::Byte),
+      ('c', Some(string_quote!())) => return self.string(reader, StringPrefix::C),
+      ('b', Some(char_quote!())) => return self.character(reader, true),
+      _ => {},
+    };
+
+    match ch {
       whitespace!() => self.whitespace(reader),
-      ident!() => self.identifier(reader),
-      operator!() => self.operator(reader),
       decimal!() => self.numeric(reader),
-      _ => todo!("{:?}", item.ch),
+      string_quote!() => self.string(reader, StringPrefix::Plain),
+      char_quote!() => self.character(reader, false),
+      ch if ident::is_ident_start(ch) => self.identifier(reader),
+      ch if operator::LEXEMES.starts_with(ch) => self.operator(reader),
+      ch => {
+        reader.seek();
+
+        UnknownCharacterSnafu { ch, span: start.into_span(reader.position) }.fail()?
+      },
     }
   }
 }
@@ -4,10 +4,15 @@ use crate::tokenizer::{
   Tokenizer,
   TokenKind,
   NumericKind,
+  NumericSuffix,
   error::*
 };
+use crate::tokenizer::impls::ident::{
+  is_ident_start,
+  is_ident_continue,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum NumericState {
   Binary,
   Octal,
@@ -16,54 +21,115 @@ pub(crate) enum NumericState {
 }
 
 impl Tokenizer {
+  /// Reads a run of digits and `_` separators.  Digits outside of the base
+  /// are still consumed here so that they show up in the error later on.
+  fn digits<'a>(reader: &mut PeekReader<'a>, state: NumericState) -> &'a str {
+    let start = reader.position;
+
+    reader.seek_while(|ch| matches!(
+      (state, ch),
+      (NumericState::Hexadecimal, hexademical!()) | (_, decimal!() | '_')
+    ));
+
+    reader.slice(start, reader.position)
+  }
+
   pub(in crate::tokenizer) fn numeric(&mut self, reader: &mut PeekReader) -> Result {
-    let mut content = String::new();
-    let mut state = None;
+    trace!("Tokenizer::numeric");
 
     let start = reader.span_start();
 
-    loop {
-      let Some(item) = reader.next() else {
-        break;
+    let Some(decimal!()) = reader.peek() else {
+      return ExpectedSnafu { what: What::Numeric, span: start.into_span(reader.position) }.fail()?;
+    };
+
+    let state = match (reader.peek(), reader.peek_nth(1)) {
+      (Some('0'), Some('b')) => Some(NumericState::Binary),
+      (Some('0'), Some('o')) => Some(NumericState::Octal),
+      (Some('0'), Some('d')) => Some(NumericState::Decimal),
+      (Some('0'), Some('x')) => Some(NumericState::Hexadecimal),
+      _ => None,
+    };
+
+    if state.is_some() {
+      reader.seek();
+      reader.seek();
+    };
+
+    let state = state.unwrap_or(NumericState::Decimal);
+
+    let mut content = String::new();
+    let mut parts = vec![];
+
+    let integer = Self::digits(reader, state);
+    content.push_str(integer);
+    parts.push(integer);
+
+    // a dot which isn't followed by a digit is member access or similar, and
+    // belongs to the next token
+    if let (Some('.'), Some(decimal!())) = (reader.peek(), reader.peek_nth(1)) {
+      reader.seek();
+
+      let fraction = Self::digits(reader, NumericState::Decimal);
+      content.push('.');
+      content.push_str(fraction);
+      parts.push(fraction);
+
+      if state != NumericState::Decimal {
+        return NonDecimalFloatSnafu { span: start.into_span(reader.position) }.fail()?;
       };
+    };
 
-      let item = item?;
-      match (&state, item.ch) {
-        | (Some(NumericState::Binary), binary!())
-        | (Some(NumericState::Octal), octal!())
-        | (Some(NumericState::Decimal), decimal!())
-        | (Some(NumericState::Hexadecimal), hexademical!())
-        | (Some(_), '_')
-        | (None, '0') => {},
-        (None, decimal!()) => {
-          state = Some(NumericState::Decimal);
-        },
-        (None, 'b' | 'o' | 'd' | 'x') if content == "0" => {
-          state = Some({
-            match item.ch {
-              'b' => NumericState::Binary,
-              'o' => NumericState::Octal,
-              'd' => NumericState::Decimal,
-              'x' => NumericState::Hexadecimal,
-              _ => unreachable!(),
-            }
-          });
-
-          content.clear();
-          continue;
-        },
-        _ => break,
+    if state == NumericState::Decimal {
+      let exponent = match (reader.peek(), reader.peek_nth(1), reader.peek_nth(2)) {
+        (Some('e' | 'E'), Some(decimal!()), _) => Some(None),
+        (Some('e' | 'E'), Some(sign @ ('+' | '-')), Some(decimal!())) => Some(Some(sign)),
+        _ => None,
       };
 
-      content.push(item.ch);
+      if let Some(sign) = exponent {
+        reader.seek();
+        content.push('e');
+
+        if let Some(sign) = sign {
+          reader.seek();
+          content.push(sign);
+        };
+
+        let exponent = Self::digits(reader, NumericState::Decimal);
+        content.push_str(exponent);
+        parts.push(exponent);
+      };
     };
 
-    if content.is_empty() {
-      return ExpectedSnafu { what: What::Numeric }.fail()?;
+    let mut suffix = None;
+
+    if reader.peek().is_some_and(is_ident_start) {
+      let suffix_start = reader.span_start();
+      reader.seek_while(is_ident_continue);
+
+      let name = reader.slice(suffix_start.position, reader.position);
+
+      let Some(numeric_suffix) = NumericSuffix::from_str(name) else {
+        return InvalidSuffixSnafu { suffix: name, span: suffix_start.into_span(reader.position) }.fail()?;
+      };
+
+      suffix = Some(numeric_suffix);
     };
 
-    let state = state.unwrap_or(NumericState::Decimal);
-    let kind = NumericKind::from_state_and_content(state, &content)?;
+    let span = start.into_span(reader.position);
+
+    if parts.iter().any(|part| part.starts_with('_') || part.ends_with('_')) {
+      return MisplacedUnderscoreSnafu { span }.fail()?;
+    };
+
+    let kind = NumericKind::from_state_and_content(state, &content, suffix, span)?;
+
+    if let (NumericKind::Float(..), Some(suffix)) = (&kind, suffix) {
+      if !suffix.is_float() {
+        return InvalidSuffixSnafu { suffix: suffix.to_str(), span }.fail()?;
+      };
+    };
 
     self.push_tok(TokenKind::Numeric(kind), start, reader.position);
 
@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+
+use enum_iterator::all;
+use lazy_static::lazy_static;
+
 use crate::{Result, ok};
 use crate::tokenizer::{
   PeekReader,
@@ -5,81 +10,117 @@ use crate::tokenizer::{
   TokenKind,
   Operator,
   Punctuation,
-  SpanStart,
   error::*,
 };
 
-impl Tokenizer {
-  pub(in crate::tokenizer) fn operator(&mut self, reader: &mut PeekReader) -> Result {
-    trace!("Tokenizer::operator");
+#[derive(Debug, Clone, Copy)]
+enum Lexeme {
+  Operator(Operator),
+  Punctuation(Punctuation),
+}
+
+/// A trie over the text of every [`Operator`] and [`Punctuation`], which is
+/// built straight from their tables in `consts.rs`.
+#[derive(Default)]
+pub(in crate::tokenizer) struct LexemeTrie {
+  lexeme: Option<Lexeme>,
+  children: HashMap<char, LexemeTrie>,
+}
+
+lazy_static! {
+  pub(in crate::tokenizer) static ref LEXEMES: LexemeTrie = {
+    let mut trie = LexemeTrie::default();
 
-    let Some(item) = reader.peek()? else {
-      return ExpectedSnafu { what: What::Operator }.fail()?;
+    for operator in all::<Operator>() {
+      trie.insert(operator.to_str(), Lexeme::Operator(operator));
     };
 
-    let start = SpanStart(item.position);
-    let mut end = start.0;
+    for punctuation in all::<Punctuation>() {
+      trie.insert(punctuation.to_str(), Lexeme::Punctuation(punctuation));
+    };
 
-    let mut content = String::new();
+    trie
+  };
+}
 
-    loop {
-      let Some(item) = reader.next() else {
-        break;
-      };
-      let item = item?;
+impl From<Lexeme> for TokenKind {
+  fn from(lexeme: Lexeme) -> Self {
+    match lexeme {
+      Lexeme::Operator(operator) => TokenKind::Operator(operator),
+      Lexeme::Punctuation(punctuation) => TokenKind::Punctuation(punctuation),
+    }
+  }
+}
+
+impl LexemeTrie {
+  fn insert(&mut self, text: &str, lexeme: Lexeme) {
+    let node = text.chars().fold(self, |node, ch| node.children.entry(ch).or_default());
 
-      end = item.position;
-      content.push(item.ch);
+    assert!(node.lexeme.is_none(), "duplicate operator or punctuation {text:?}");
+    node.lexeme = Some(lexeme);
+  }
 
-      let Some(peek) = reader.peek()? else {
+  pub(in crate::tokenizer) fn starts_with(&self, ch: char) -> bool {
+    self.children.contains_key(&ch)
+  }
+
+  /// Finds the longest lexeme that the reader is positioned on, along with
+  /// its length in characters.
+  fn longest_match(&self, reader: &PeekReader) -> Option<(usize, Lexeme)> {
+    let mut node = self;
+    let mut longest = None;
+    let mut lookahead = 0;
+
+    while let Some(ch) = reader.peek_nth(lookahead) {
+      let Some(child) = node.children.get(&ch) else {
         break;
       };
 
-      match (content.as_str(), peek.ch) {
-        | ("%", '=')
-        | ("^", '^' | '=')
-        | ("^^", '=')
-        | ("&", '&' | '=')
-        | ("&&", '=')
-        | ("*", '*' | '=')
-        | ("**", '=')
-        | ("-", '=' | '-' | '>')
-        | ("+", '=' | '+')
-        | ("=", '=')
-        | ("|", '|' | '=')
-        | ("||", '|' | '=')
-        | ("<", '<' | '=')
-        | ("<<", '<' | '=')
-        | ("<<<", '=')
-        | (">", '>' | '=')
-        | (">>", '>' | '=')
-        | (">>>", '=')
-        | ("/", '/' | '*' | '=')
-        | (":", ':')
-        | (".", '.')
-        | ("..", '.')
-        => {},
-        ("//", _) => {
-          self.line_comment(reader)?;
-          return ok;
-        },
-        ("/*", _) => todo!("multiline comment"),
-        _ => break,
+      node = child;
+      lookahead += 1;
+
+      if let Some(lexeme) = node.lexeme {
+        longest = Some((lookahead, lexeme));
       };
     };
 
-    let kind = if let Some(op) = Operator::from_str(&content) {
-      TokenKind::Operator(op)
-    } else if let Some(punct) = Punctuation::from_str(&content) {
-      TokenKind::Punctuation(punct)
-    } else {
-      // TODO: `unrecognized` isn't strictly the same as `expected`
+    longest
+  }
+}
+
+impl Tokenizer {
+  pub(in crate::tokenizer) fn operator(&mut self, reader: &mut PeekReader) -> Result {
+    trace!("Tokenizer::operator");
+
+    let start = reader.span_start();
+
+    match (reader.peek(), reader.peek_nth(1)) {
+      (Some('/'), Some('/')) => {
+        reader.seek();
+        reader.seek();
+
+        return self.line_comment(reader, start);
+      },
+      (Some('/'), Some('*')) => {
+        reader.seek();
+        reader.seek();
+
+        return self.block_comment(reader, start);
+      },
+      _ => {},
+    };
+
+    let Some((length, lexeme)) = LEXEMES.longest_match(reader) else {
+      reader.seek();
+
+      return UnexpectedSnafu { what: What::Operator, span: start.into_span(reader.position) }.fail()?;
+    };
 
-      return ExpectedSnafu { what: What::Operator }.fail()?;
-      // return OtherSnafu { err: format!("unrecognized operator: {content:?}") }.fail()?;
+    for _ in 0..length {
+      reader.seek();
     };
 
-    self.push_tok(kind, start, end);
+    self.push_tok(lexeme.into(), start, reader.position);
 
     ok
   }
@@ -0,0 +1,102 @@
+use crate::{Result, ok};
+use crate::tokenizer::{
+  PeekReader,
+  Tokenizer,
+  TokenKind,
+  StringKind,
+  SpanStart,
+  error::*,
+};
+use crate::tokenizer::impls::escape::Escape;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum StringPrefix {
+  Plain,
+  Byte,
+  C,
+}
+
+impl Tokenizer {
+  /// Reads a string literal, including its prefix if there is one.
+  pub(in crate::tokenizer) fn string(&mut self, reader: &mut PeekReader, prefix: StringPrefix) -> Result {
+    trace!("Tokenizer::string");
+
+    let start = reader.span_start();
+
+    if let StringPrefix::Byte | StringPrefix::C = prefix {
+      reader.seek();
+    };
+
+    let Some(string_quote!()) = reader.next() else {
+      return ExpectedSnafu { what: What::String, span: reader.span_start().into_span(reader.position) }.fail()?;
+    };
+
+    let mut content = Vec::<u8>::new();
+
+    // errors inside of the literal don't stop it from being read up to its
+    // closing quote, which keeps the rest of the file in sync
+    let mut failed = false;
+
+    loop {
+      let position = reader.position;
+
+      let Some(ch) = reader.next() else {
+        return UnterminatedSnafu { what: What::String, span: start.into_span(reader.position) }.fail()?;
+      };
+
+      let result = match (prefix, ch) {
+        (_, string_quote!()) => break,
+        (_, '\\') => {
+          let escape_start = reader.span_start_at(position);
+
+          self.string_escape(reader, escape_start, prefix, &mut content)
+        },
+        (StringPrefix::Byte, ch) if !ch.is_ascii() => NonAsciiSnafu {
+          what: What::ByteString,
+          ch,
+          span: reader.span_start_at(position).into_span(reader.position),
+        }.fail().map_err(Into::into),
+        (_, ch) => {
+          content.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+
+          ok
+        },
+      };
+
+      if let Err(err) = result {
+        self.errors.push(err);
+        failed = true;
+      };
+    };
+
+    let kind = if failed {
+      TokenKind::Error
+    } else {
+      TokenKind::String(StringKind::from_prefix_and_content(prefix, content))
+    };
+
+    self.push_tok(kind, start, reader.position);
+
+    ok
+  }
+
+  fn string_escape(&mut self, reader: &mut PeekReader, escape_start: SpanStart, prefix: StringPrefix, content: &mut Vec<u8>) -> Result {
+    let escape = self.escape(reader, escape_start)?;
+    let span = escape_start.into_span(reader.position);
+
+    match prefix {
+      StringPrefix::Plain => {
+        let ch = escape.into_char(span)?;
+
+        content.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+      },
+      StringPrefix::Byte => content.push(escape.into_byte(span)?),
+      StringPrefix::C => match escape {
+        Escape::Byte(byte) => content.push(byte),
+        Escape::Char(ch) => content.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
+      },
+    };
+
+    ok
+  }
+}
@@ -3,32 +3,16 @@ use crate::tokenizer::{
   PeekReader,
   Tokenizer,
   TokenKind,
-  SpanStart,
 };
 
 impl Tokenizer {
   pub(in crate::tokenizer) fn whitespace(&mut self, reader: &mut PeekReader) -> Result {
     trace!("Tokenizer::whitespace");
 
-    let Some(item) = reader.next() else {
-      return ok;
-    };
+    let start = reader.span_start();
+    reader.seek_while(|ch| matches!(ch, whitespace!()));
 
-    let item = item?;
-
-    let start = SpanStart(item.position);
-    let mut end = item.position;
-
-    while let Some(item) = reader.peek()? {
-      let whitespace!() = item.ch else {
-        break;
-      };
-
-      end = item.position;
-      reader.seek();
-    };
-
-    self.push_tok(TokenKind::Whitespace, start, end);
+    self.push_tok(TokenKind::Whitespace, start, reader.position);
 
     ok
   }
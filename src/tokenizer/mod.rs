@@ -4,20 +4,35 @@ mod peek_reader;
 
 mod impls;
 
-pub(self) use peek_reader::PeekReader;
-use peek_reader::ReaderItem;
+#[cfg(test)]
+mod tests;
+
+use peek_reader::PeekReader;
 pub(crate) use token::*;
 
-use crate::Result;
+use std::collections::HashMap;
+
+use crate::{Result, ok};
 use crate::compiler::{
   Compiler,
   CompilerWorkflow,
+  FileId,
+  Interner,
   TakenCompilerModule,
-  error::IOSnafu,
+  error::CompilerError,
 };
+use crate::logger::DIAGNOSTICS;
 
 pub(super) struct Tokenizer {
   tokens: Vec<Token>,
+  errors: Vec<CompilerError>,
+  /// Lint findings, which are reported but don't stop compilation.
+  warnings: Vec<CompilerError>,
+  interner: Interner,
+  mixed_script_lint: bool,
+  /// The first name seen with each confusable skeleton, for the mixed-script
+  /// lint.
+  skeletons: HashMap<String, String>,
 }
 
 impl Tokenizer {
@@ -31,6 +46,68 @@ impl Tokenizer {
 
     self.tokens.push(token);
   }
+
+  /// Skips past whatever caused an error, covering it with an error token, so
+  /// that tokenizing can carry on and find the rest of the errors.
+  fn recover(&mut self, reader: &mut PeekReader, start: SpanStart, err: CompilerError) {
+    debug!("Tokenizer::recover {err:?}");
+
+    // the tokenizer might have pushed some tokens before it failed
+    let start = match self.tokens.last() {
+      Some(last) if last.span.end > start.position => reader.span_start_at(last.span.end),
+      _ => start,
+    };
+
+    // always make progress, even if nothing was consumed
+    if reader.position == start.position {
+      reader.seek();
+    };
+
+    self.push_tok(TokenKind::Error, start, reader.position);
+    self.errors.push(err);
+  }
+
+  /// Tokenizes a whole source file.  Every byte of `source` ends up in exactly
+  /// one token, including whitespace, comments and errors, so the text of the
+  /// tokens (as given by their spans) always reproduces the file.
+  fn tokenize_source(&mut self, file: FileId, source: &str) -> Result {
+    let mut reader = PeekReader::new(file, source);
+
+    self.shebang(&mut reader)?;
+
+    while reader.peek().is_some() {
+      let start = reader.span_start();
+
+      if let Err(err) = self.base(&mut reader) {
+        self.recover(&mut reader, start, err);
+      };
+    };
+
+    ok
+  }
+}
+
+/// Tokenizes a file on its own, outside of the compiler's pipeline.
+pub(crate) fn tokenize_file(source_map: &crate::compiler::SourceMap, file: FileId, interner: &mut Interner) -> Result<Vec<Token>> {
+  let mut tokenizer = Tokenizer {
+    tokens: vec![],
+    errors: vec![],
+    warnings: vec![],
+    interner: std::mem::take(interner),
+    mixed_script_lint: false,
+    skeletons: HashMap::new(),
+  };
+
+  let result = tokenizer.tokenize_source(file, source_map.source(file));
+  *interner = tokenizer.interner;
+
+  result?;
+
+  if !tokenizer.errors.is_empty() {
+    return Err(tokenizer.errors.into());
+  };
+
+  Ok(tokenizer.tokens)
 }
 
 impl<W: CompilerWorkflow> crate::compiler::Tokenize<W> for Tokenizer {
@@ -39,31 +116,32 @@ impl<W: CompilerWorkflow> crate::compiler::Tokenize<W> for Tokenizer {
   fn new() -> Self {
     Self {
       tokens: vec![],
+      errors: vec![],
+      warnings: vec![],
+      interner: Interner::default(),
+      mixed_script_lint: false,
+      skeletons: HashMap::new(),
     }
   }
 
   fn tokenize(mut self, compiler: &mut Compiler<W>, module: TakenCompilerModule<W>) -> Result<Self::Out> {
-    let path = compiler.store.get_module(&module.handle).path.as_path();
-    let file = match std::fs::File::open(path) {
-      Ok(x) => x,
-      Err(err) => return IOSnafu { err: err.to_string() }.fail()?,
-    };
+    let file = compiler.store.get_module(&module.handle).file;
+    self.mixed_script_lint = compiler.settings.mixed_script_lint;
 
-    let buf_reader = std::io::BufReader::new(file);
+    // names are interned into the compiler's interner, so that they stay
+    // comparable across modules
+    std::mem::swap(&mut self.interner, &mut compiler.interner);
+    let result = self.tokenize_source(file, compiler.source_map.source(file));
+    std::mem::swap(&mut self.interner, &mut compiler.interner);
 
-    let mut reader = utf8_read::Reader::new(buf_reader);
-    let mut reader = reader
-      .into_iter()
-      .enumerate()
-      .map(|(position, ch)| match ch {
-        Ok(ch) => Ok(ReaderItem { position, ch, }),
-        Err(err) => IOSnafu { err: err.to_string() }.fail()?,
-      });
+    result?;
 
-    let mut reader = PeekReader::new(&mut reader);
+    for warning in self.warnings.drain(..) {
+      warn!(target: DIAGNOSTICS, "{}", compiler.source_map.annotate(warning));
+    };
 
-    while reader.peek()?.is_some() {
-      self.base(&mut reader)?;
+    if !self.errors.is_empty() {
+      return Err(self.errors.into());
     };
 
     Ok(self.tokens)
@@ -29,6 +29,16 @@ macro_rules! hexademical {
 }
 
 #[macro_export]
-macro_rules! operator {
-  () => { '~' | '!' | '%' | '^' | '&' | '-' | '+' | '=' | '|' | '<' | '>' | '/' | '?' | ':' | ';' | ',' | '.' };
+macro_rules! string_quote {
+  () => { '"' };
+}
+
+#[macro_export]
+macro_rules! char_quote {
+  () => { '\'' };
+}
+
+#[macro_export]
+macro_rules! placeholder {
+  () => { '$' };
 }
@@ -1,76 +1,90 @@
-use crate::Result;
+use crate::compiler::FileId;
 use crate::tokenizer::SpanStart;
 
-#[derive(Debug, Clone, Copy)]
-pub(super) struct ReaderItem {
-  pub position: usize,
-  pub ch: char,
-}
-
+/// A cursor over a whole source file in memory.  It scans the underlying bytes
+/// directly, only decoding a character when it isn't ASCII, and can look ahead
+/// any number of characters without consuming them.
 pub(super) struct PeekReader<'a> {
-  reader: &'a mut dyn Iterator<Item = Result<ReaderItem>>,
-  peek_buffer: Option<ReaderItem>,
+  file: FileId,
+  source: &'a str,
+  /// Byte offset of the first character that has not been consumed yet, so
+  /// that it can be used as an exclusive span end.
   pub position: usize,
 }
 
 impl<'a> PeekReader<'a> {
-  pub(super) fn new(reader: &'a mut dyn Iterator<Item = Result<ReaderItem>>) -> Self {
+  pub(super) fn new(file: FileId, source: &'a str) -> Self {
     Self {
-      reader,
-      peek_buffer: None,
+      file,
+      source,
       position: 0,
     }
   }
 
-  pub(super) fn seek(&mut self) {
-    if self.peek_buffer.is_some() {
-      self.peek_buffer = None;
-    } else {
-      self.next();
+  fn char_at(&self, position: usize) -> Option<char> {
+    let byte = *self.source.as_bytes().get(position)?;
+
+    if byte.is_ascii() {
+      return Some(byte as char);
     };
+
+    self.source[position..].chars().next()
   }
 
-  pub(super) fn peek(&mut self) -> Result<Option<ReaderItem>> {
-    if let Some(buffered) = self.peek_buffer {
-      return Ok(Some(buffered));
-    };
+  /// Looks at the character `n` characters ahead, where `0` is the next one.
+  pub(super) fn peek_nth(&self, n: usize) -> Option<char> {
+    let mut position = self.position;
 
-    let Some(item) = self.next() else {
-      return Ok(None);
+    for _ in 0..n {
+      position += self.char_at(position)?.len_utf8();
     };
 
-    let item = item?;
-    self.peek_buffer = Some(item);
+    self.char_at(position)
+  }
+
+  pub(super) fn peek(&self) -> Option<char> {
+    self.char_at(self.position)
+  }
+
+  pub(super) fn seek(&mut self) {
+    self.next();
+  }
+
+  /// Consumes characters for as long as they match `predicate`.
+  pub(super) fn seek_while(&mut self, predicate: impl Fn(char) -> bool) {
+    while let Some(ch) = self.peek() {
+      if !predicate(ch) {
+        break;
+      };
+
+      self.position += ch.len_utf8();
+    };
+  }
 
-    Ok(Some(item))
+  /// Borrows the source text between two byte offsets.
+  pub(super) fn slice(&self, start: usize, end: usize) -> &'a str {
+    &self.source[start..end]
   }
 
   pub(super) fn span_start(&self) -> SpanStart {
-    SpanStart(self.position)
+    self.span_start_at(self.position)
+  }
+
+  pub(super) fn span_start_at(&self, position: usize) -> SpanStart {
+    SpanStart {
+      file: self.file,
+      position,
+    }
   }
 }
 
 impl Iterator for PeekReader<'_> {
-  type Item = Result<ReaderItem>;
+  type Item = char;
 
   fn next(&mut self) -> Option<Self::Item> {
-    let (message, result) = {
-      if let Some(buffered) = self.peek_buffer {
-        self.peek_buffer = None;
-
-        ("buffered", Some(Ok(buffered)))
-      } else {
-        let next = self.reader.next();
-
-        if next.as_ref().is_some_and(|next| next.is_ok()) {
-          self.position += 1;
-        };
-
-        ("read", next)
-      }
-    };
+    let ch = self.peek()?;
+    self.position += ch.len_utf8();
 
-    trace!("PeekReader::next {message}   \t-> {result:?}");
-    result
+    Some(ch)
   }
 }
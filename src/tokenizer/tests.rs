@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+use std::path::{
+  Path,
+  PathBuf,
+};
+
+use crate::compiler::{
+  Interner,
+  SourceMap,
+  error::CompilerError,
+};
+use crate::tokenizer::{
+  Keyword,
+  NumericKind,
+  NumericSuffix,
+  Operator,
+  Punctuation,
+  StringKind,
+  TokenKind,
+  Tokenizer,
+};
+
+fn snippets(dir: &Path, paths: &mut Vec<PathBuf>) {
+  for entry in std::fs::read_dir(dir).unwrap() {
+    let path = entry.unwrap().path();
+
+    if path.is_dir() {
+      snippets(&path, paths);
+    } else if path.extension().is_some_and(|extension| extension == "zy") {
+      paths.push(path);
+    };
+  };
+}
+
+fn all_snippets() -> Vec<PathBuf> {
+  let mut paths = vec![];
+  snippets(&Path::new(env!("CARGO_MANIFEST_DIR")).join("snippets"), &mut paths);
+  paths.sort();
+
+  assert!(!paths.is_empty(), "no snippets found");
+
+  paths
+}
+
+fn tokenizer() -> Tokenizer {
+  Tokenizer {
+    tokens: vec![],
+    errors: vec![],
+    warnings: vec![],
+    interner: Interner::default(),
+    mixed_script_lint: true,
+    skeletons: HashMap::new(),
+  }
+}
+
+/// Snippets that are meant not to compile, with the errors they fail with.
+const FAILING_SNIPPETS: &[(&str, &[&str])] = &[
+  ("01_assn.zy", &["Token error: integer literal out of range for `u8`"]),
+];
+
+/// Tokenizes a snippet, keeping any errors around to be checked.
+fn tokenize(source_map: &mut SourceMap, path: &Path) -> crate::Result<Tokenizer> {
+  let file = source_map.load(path)?;
+  let mut tokenizer = tokenizer();
+
+  tokenizer.tokenize_source(file, source_map.source(file))?;
+
+  Ok(tokenizer)
+}
+
+/// Tokenizes `source` as a file of its own, keeping any errors around to be
+/// checked.
+fn tokenize_text(source: &str) -> Tokenizer {
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("text.zy".into(), source.into());
+
+  let mut tokenizer = tokenizer();
+  tokenizer.tokenize_source(file, source_map.source(file)).unwrap();
+
+  tokenizer
+}
+
+#[test]
+fn lossless_snippets() {
+  let mut source_map = SourceMap::new();
+
+  for path in all_snippets() {
+    let tokenizer = tokenize(&mut source_map, &path)
+      .unwrap_or_else(|err| panic!("{path:?} failed to tokenize: {err}"));
+
+    let errors = tokenizer.errors.iter()
+      .map(ToString::to_string)
+      .collect::<Vec<_>>();
+    let expected = FAILING_SNIPPETS.iter()
+      .find(|(name, _)| path.ends_with(name))
+      .map_or(&[][..], |(_, errors)| errors);
+
+    assert_eq!(errors, expected, "unexpected errors in {path:?}");
+
+    let file = source_map.load(&path).unwrap();
+    let source = source_map.source(file);
+
+    let mut position = 0;
+    let mut rebuilt = String::new();
+
+    for token in &tokenizer.tokens {
+      assert_eq!(token.span.start, position, "gap or overlap before {token:?} in {path:?}");
+      assert!(token.span.end > token.span.start, "empty token {token:?} in {path:?}");
+
+      rebuilt.push_str(&source[token.span.start..token.span.end]);
+      position = token.span.end;
+    };
+
+    assert_eq!(position, source.len(), "trailing input was not tokenized in {path:?}");
+    assert_eq!(rebuilt, source, "{path:?} did not round-trip");
+  };
+}
+
+#[test]
+fn snippet_keywords() {
+  let keywords = enum_iterator::all::<Keyword>()
+    .map(Keyword::to_str)
+    .collect::<Vec<_>>();
+
+  let mut source_map = SourceMap::new();
+  let mut seen = 0;
+
+  for path in all_snippets() {
+    let tokens = tokenize(&mut source_map, &path).unwrap().tokens;
+
+    let file = source_map.load(&path).unwrap();
+    let source = source_map.source(file);
+
+    for token in &tokens {
+      let text = &source[token.span.start..token.span.end];
+
+      if !keywords.contains(&text) {
+        continue;
+      };
+
+      let TokenKind::Keyword(keyword) = token.kind else {
+        panic!("{text:?} in {path:?} was tokenized as {:?}", token.kind);
+      };
+
+      assert_eq!(keyword.to_str(), text);
+      seen += 1;
+    };
+  };
+
+  assert!(seen > 0, "no keywords appear in any snippet");
+}
+
+#[test]
+fn contextual_keywords() {
+  let mut interner = Interner::default();
+
+  for keyword in enum_iterator::all::<Keyword>() {
+    let kind = TokenKind::Keyword(keyword);
+
+    if keyword.is_contextual() {
+      let name = kind.as_identifier(&mut interner).unwrap();
+      assert_eq!(interner.resolve(name), keyword.to_str());
+    } else {
+      assert_eq!(kind.as_identifier(&mut interner), None);
+    };
+  };
+}
+
+#[test]
+fn recovers_from_every_error() {
+  let source = "a := '';\nb := \"\\q \\x80\";\nc := 1_ # 0x1.8;\nd := b'\\u{e9}' 'ok' \"open";
+
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("recovery.zy".into(), source.into());
+
+  let mut tokenizer = tokenizer();
+  tokenizer.tokenize_source(file, source_map.source(file)).unwrap();
+
+  let errors = tokenizer.errors.iter()
+    .map(|err| {
+      let span = err.span().unwrap();
+      let (start, _) = source_map.locate(&span);
+
+      (start.line, &source[span.start..span.end])
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(errors, [
+    (1, "''"),
+    (2, "\\q"),
+    (2, "\\x80"),
+    (3, "1_"),
+    (3, "#"),
+    (3, "0x1.8"),
+    (4, "\\u{e9}"),
+    (4, "'ok'"),
+    (4, "\"open"),
+  ]);
+
+  let rebuilt = tokenizer.tokens.iter()
+    .map(|token| &source[token.span.start..token.span.end])
+    .collect::<String>();
+
+  assert_eq!(rebuilt, source);
+}
+
+#[test]
+fn unicode_identifiers() {
+  // the second `naïve` is spelled with a combining diaeresis, and `pаy` has
+  // a Cyrillic `а`
+  let source = "naïve := 数据 + nai\u{308}ve + _ß2 + pаy;";
+
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("unicode.zy".into(), source.into());
+
+  let mut tokenizer = tokenizer();
+  tokenizer.tokenize_source(file, source_map.source(file)).unwrap();
+
+  assert!(tokenizer.errors.is_empty());
+
+  let names = tokenizer.tokens.iter()
+    .filter_map(|token| match token.kind {
+      TokenKind::Identifier(name) => Some(name),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  let resolved = names.iter()
+    .map(|name| tokenizer.interner.resolve(*name))
+    .collect::<Vec<_>>();
+
+  assert_eq!(resolved, ["naïve", "数据", "naïve", "_ß2", "pаy"]);
+  assert_eq!(names[0], names[2]);
+
+  let warnings = tokenizer.warnings.iter()
+    .map(|warning| {
+      let span = warning.span().unwrap();
+
+      &source[span.start..span.end]
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(warnings, ["pаy"]);
+}
+
+#[test]
+fn confusable_identifiers() {
+  // `рау` is all Cyrillic, `pаy` and `lіne` each have one Cyrillic letter,
+  // and `rn` and `m` only look alike in plain ASCII
+  let tokenizer = tokenize_text("pay := 1; рау := 2; pаy := 3; rn := m; lіne := line;");
+
+  let warnings = tokenizer.warnings.iter()
+    .map(|warning| warning.to_string())
+    .collect::<Vec<_>>();
+
+  assert_eq!(warnings, [
+    "identifier \"рау\" can be confused with \"pay\"",
+    "identifier \"pаy\" mixes characters from different scripts",
+    "identifier \"pаy\" can be confused with \"pay\"",
+    "identifier \"lіne\" mixes characters from different scripts",
+    "identifier \"line\" can be confused with \"lіne\"",
+  ]);
+}
+
+#[test]
+fn macro_names() {
+  let source = "defer! s { drop! $; };\nlib!(\"c\") libc {};\nallow! non_lowercase_type:\na := !b; c := d !e; while!done {};";
+
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("macros.zy".into(), source.into());
+
+  let mut tokenizer = tokenizer();
+  tokenizer.tokenize_source(file, source_map.source(file)).unwrap();
+
+  assert!(tokenizer.errors.is_empty());
+
+  let macros = tokenizer.tokens.iter()
+    .filter_map(|token| match token.kind {
+      TokenKind::MacroName(name) => Some((tokenizer.interner.resolve(name), &source[token.span.start..token.span.end])),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(macros, [
+    ("defer", "defer!"),
+    ("drop", "drop!"),
+    ("lib", "lib!"),
+    ("allow", "allow!"),
+  ]);
+
+  let nots = tokenizer.tokens.iter()
+    .filter(|token| matches!(token.kind, TokenKind::Operator(Operator::LogicalNot)))
+    .count();
+
+  assert_eq!(nots, 3);
+}
+
+#[test]
+fn source_file_hygiene() {
+  let source = "\u{feff}#!/usr/bin/env lazy\r\nmain := 1;\r\n// done\r\n@";
+
+  let mut source_map = SourceMap::new();
+  let file = source_map.add("script.zy".into(), source.into());
+  let source = source_map.source(file);
+
+  assert!(source.starts_with("#!"));
+
+  let mut tokenizer = tokenizer();
+  tokenizer.tokenize_source(file, source).unwrap();
+
+  let comments = tokenizer.tokens.iter()
+    .filter(|token| matches!(token.kind, TokenKind::Comment))
+    .map(|token| &source[token.span.start..token.span.end])
+    .collect::<Vec<_>>();
+
+  assert_eq!(comments, ["#!/usr/bin/env lazy\r", "// done\r"]);
+
+  let span = tokenizer.errors[0].span().unwrap();
+  let (start, _) = source_map.locate(&span);
+
+  assert_eq!((start.line, start.column), (4, 1));
+  assert_eq!(source_map.line_text(&span), "@");
+}
+
+#[test]
+fn invalid_utf8() {
+  let path = std::env::temp_dir().join(format!("lazy-invalid-utf8-{}.zy", std::process::id()));
+  std::fs::write(&path, b"main := \"\xff\";\n").unwrap();
+
+  let result = SourceMap::new().load(&path);
+  std::fs::remove_file(&path).unwrap();
+
+  match result {
+    Err(CompilerError::InvalidUtf8 { path: err_path, offset }) => {
+      assert_eq!(err_path, path);
+      assert_eq!(offset, 9);
+    },
+    Err(err) => panic!("unexpected error {err}"),
+    Ok(_) => panic!("invalid UTF-8 was accepted"),
+  };
+}
+
+#[test]
+fn string_literals() {
+  let tokenizer = tokenize_text(r#"a := "\x61\u{1F60A}\\\"" b"\x61\xff\"" c"\u{e9}\0";"#);
+
+  assert!(tokenizer.errors.is_empty());
+
+  let strings = tokenizer.tokens.iter()
+    .filter_map(|token| match &token.kind {
+      TokenKind::String(string) => Some(string),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  let [StringKind::Plain(plain), StringKind::Byte(byte), StringKind::C(c)] = strings[..] else {
+    panic!("strings were tokenized as {strings:?}");
+  };
+
+  assert_eq!(plain, "a\u{1F60A}\\\"");
+  assert_eq!(byte, b"a\xff\"");
+  assert_eq!(c, "\u{e9}\0".as_bytes());
+}
+
+#[test]
+fn character_literals() {
+  let tokenizer = tokenize_text("'\\u{2705}' '✅' b'\\x61' b' ' 'ab' b'é'");
+
+  let chars = tokenizer.tokens.iter()
+    .filter(|token| !matches!(token.kind, TokenKind::Whitespace))
+    .map(|token| format!("{:?}", token.kind))
+    .collect::<Vec<_>>();
+
+  assert_eq!(chars, ["Char('✅')", "Char('✅')", "ByteChar(97)", "ByteChar(32)", "Error", "Error"]);
+
+  let errors = tokenizer.errors.iter()
+    .map(|err| err.to_string())
+    .collect::<Vec<_>>();
+
+  assert_eq!(errors.len(), 2);
+  assert!(errors[0].contains("must contain exactly one character"), "{}", errors[0]);
+  assert!(errors[1].contains("out of range"), "{}", errors[1]);
+}
+
+#[test]
+fn comments() {
+  let source = "/* outer /* inner */ outer */\n/// doc\n//// line\n/** block doc */ /**/ /*** block */\na /* open /* nested */";
+  let tokenizer = tokenize_text(source);
+
+  let comments = tokenizer.tokens.iter()
+    .filter_map(|token| {
+      let doc = match token.kind {
+        TokenKind::Comment => false,
+        TokenKind::DocComment => true,
+        _ => return None,
+      };
+
+      Some((doc, &source[token.span.start..token.span.end]))
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(comments, [
+    (false, "/* outer /* inner */ outer */"),
+    (true, "/// doc"),
+    (false, "//// line"),
+    (true, "/** block doc */"),
+    (false, "/**/"),
+    (false, "/*** block */"),
+  ]);
+
+  let [err] = &tokenizer.errors[..] else {
+    panic!("expected one error, got {:?}", tokenizer.errors);
+  };
+
+  let span = err.span().unwrap();
+
+  assert_eq!(err.to_string(), "Token error: unterminated block comment");
+  assert_eq!(&source[span.start..span.end], "/* open /* nested */");
+}
+
+#[test]
+fn numeric_suffixes() {
+  let tokenizer = tokenize_text("1234usize 5678u64 2.5f32 7i8 9");
+
+  assert!(tokenizer.errors.is_empty());
+
+  let numbers = tokenizer.tokens.iter()
+    .filter_map(|token| match token.kind {
+      TokenKind::Numeric(NumericKind::Integer(value, suffix)) => Some((value as f64, suffix)),
+      TokenKind::Numeric(NumericKind::Float(value, suffix)) => Some((value, suffix)),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(numbers, [
+    (1234.0, Some(NumericSuffix::Usize)),
+    (5678.0, Some(NumericSuffix::U64)),
+    (2.5, Some(NumericSuffix::F32)),
+    (7.0, Some(NumericSuffix::I8)),
+    (9.0, None),
+  ]);
+
+  let source = "255u8 256u8 128i8 129i8 18446744073709551615u64 4294967296u32";
+  let tokenizer = tokenize_text(source);
+
+  let errors = tokenizer.errors.iter()
+    .map(|err| {
+      let span = err.span().unwrap();
+
+      (err.to_string(), &source[span.start..span.end])
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(errors, [
+    ("Token error: integer literal out of range for `u8`".to_owned(), "256u8"),
+    ("Token error: integer literal out of range for `i8`".to_owned(), "129i8"),
+    ("Token error: integer literal out of range for `u32`".to_owned(), "4294967296u32"),
+  ]);
+}
+
+#[test]
+fn exponent_floats() {
+  let tokenizer = tokenize_text("1.5e-3 2E+2 3e4");
+
+  assert!(tokenizer.errors.is_empty());
+
+  let floats = tokenizer.tokens.iter()
+    .filter_map(|token| match token.kind {
+      TokenKind::Numeric(NumericKind::Float(value, None)) => Some(value),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(floats, [1.5e-3, 2e2, 3e4]);
+}
+
+#[test]
+fn misplaced_underscores() {
+  let source = "_1 1_ 1__000 1_.5 1._5 0x_ff 1_000";
+  let tokenizer = tokenize_text(source);
+
+  let errors = tokenizer.errors.iter()
+    .map(|err| {
+      let span = err.span().unwrap();
+
+      &source[span.start..span.end]
+    })
+    .collect::<Vec<_>>();
+
+  // `_1` is an identifier, and `1._5` is `1` followed by a member access
+  assert_eq!(errors, ["1_", "1_.5", "0x_ff"]);
+}
+
+#[test]
+fn every_operator_and_punctuation() {
+  let lexemes = enum_iterator::all::<Operator>()
+    .map(|operator| (operator.to_str(), TokenKind::Operator(operator)))
+    .chain(enum_iterator::all::<Punctuation>().map(|punctuation| (punctuation.to_str(), TokenKind::Punctuation(punctuation))));
+
+  for (text, kind) in lexemes {
+    let tokenizer = tokenize_text(text);
+
+    assert!(tokenizer.errors.is_empty(), "{text:?} failed to tokenize");
+
+    let [token] = &tokenizer.tokens[..] else {
+      panic!("{text:?} was tokenized as {:?}", tokenizer.tokens);
+    };
+
+    assert_eq!(format!("{:?}", token.kind), format!("{kind:?}"), "{text:?} was tokenized as the wrong kind");
+  };
+
+  let source = "a:=b**c*d..e...";
+  let tokenizer = tokenize_text(source);
+
+  let texts = tokenizer.tokens.iter()
+    .map(|token| &source[token.span.start..token.span.end])
+    .collect::<Vec<_>>();
+
+  assert_eq!(texts, ["a", ":=", "b", "**", "c", "*", "d", "..", "e", "..."]);
+}
+
+#[test]
+fn pipes() {
+  let source = "s |>= $.double(); t|>f($)|>=g";
+  let tokenizer = tokenize_text(source);
+
+  assert!(tokenizer.errors.is_empty());
+
+  let pipes = tokenizer.tokens.iter()
+    .filter_map(|token| {
+      let kind = match token.kind {
+        TokenKind::Operator(operator @ (Operator::Pipe | Operator::PipeAssign)) => operator.to_str(),
+        TokenKind::PipePlaceholder => "placeholder",
+        _ => return None,
+      };
+
+      Some((kind, &source[token.span.start..token.span.end]))
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(pipes, [
+    ("|>=", "|>="),
+    ("placeholder", "$"),
+    ("|>", "|>"),
+    ("placeholder", "$"),
+    ("|>=", "|>="),
+  ]);
+}
+
+/// The contents and text of every inline LLVM token in `source`.
+fn inline_llvm(source: &str) -> (Tokenizer, Vec<(String, &str)>) {
+  let tokenizer = tokenize_text(source);
+
+  let blocks = tokenizer.tokens.iter()
+    .filter_map(|token| match &token.kind {
+      TokenKind::InlineLlvm(content) => Some((content.to_owned(), &source[token.span.start..token.span.end])),
+      _ => None,
+    })
+    .collect();
+
+  (tokenizer, blocks)
+}
+
+#[test]
+fn inline_llvm_block() {
+  let (tokenizer, blocks) = inline_llvm("__add := __llvm { %tmp1 = add u8 %lhs, %rhs };");
+
+  assert!(tokenizer.errors.is_empty());
+  assert_eq!(blocks, [(
+    " %tmp1 = add u8 %lhs, %rhs ".to_owned(),
+    "__llvm { %tmp1 = add u8 %lhs, %rhs }",
+  )]);
+}
+
+#[test]
+fn inline_llvm_nested_braces() {
+  let source = "__llvm {\n  %pair = type { i8, { i16 } } ; } in a comment\n  @s = constant [2 x i8] c\"}\\00\"\n} x";
+  let (tokenizer, blocks) = inline_llvm(source);
+
+  assert!(tokenizer.errors.is_empty());
+
+  let [(content, text)] = &blocks[..] else {
+    panic!("expected one block, got {blocks:?}");
+  };
+
+  assert_eq!(content, "\n  %pair = type { i8, { i16 } } ; } in a comment\n  @s = constant [2 x i8] c\"}\\00\"\n");
+  assert_eq!(*text, &source[..source.len() - " x".len()]);
+}
+
+#[test]
+fn unterminated_inline_llvm() {
+  let source = "f := __llvm { ret { i8 } void";
+  let (tokenizer, blocks) = inline_llvm(source);
+
+  assert!(blocks.is_empty());
+
+  let [err] = &tokenizer.errors[..] else {
+    panic!("expected one error, got {:?}", tokenizer.errors);
+  };
+
+  let span = err.span().unwrap();
+
+  assert_eq!(err.to_string(), "Token error: unterminated inline LLVM block");
+  assert_eq!(&source[span.start..span.end], "__llvm { ret { i8 } void");
+}
@@ -5,7 +5,7 @@ macro_rules! enum_map {
       const $ident: &str = $expr;
     )+
 
-    #[derive(Debug)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, enum_iterator::Sequence)]
     pub(crate) enum $name {
       $($ident,)+
     }
@@ -20,7 +20,7 @@ macro_rules! enum_map {
         }
       }
 
-      pub(crate) fn to_str(&self) -> &'static str {
+      pub(crate) fn to_str(self) -> &'static str {
         match self {
           $(Self::$ident => $ident,)+
         }
@@ -58,6 +58,8 @@ enum_map!(Operator {
   BitOrAssign: "|=",
   LogicalOr: "||",
   LogicalOrAssign: "||=",
+  Pipe: "|>",
+  PipeAssign: "|>=",
   LessThan: "<",
   LessThanEquals: "<=",
   BitShiftLeft: "<<",
@@ -99,6 +101,7 @@ enum_map!(Keyword {
   Export: "export",
   From: "from",
   As: "as",
+  In: "in",
   If: "if",
   Else: "else",
   Switch: "switch",
@@ -108,16 +111,81 @@ enum_map!(Keyword {
   Unless: "unless",
   Do: "do",
   While: "while",
+  Until: "until",
+  Extern: "extern",
+  Impl: "impl",
+  Test: "test",
+  True: "true",
+  False: "false",
+  This: "this",
+  ThisType: "This",
+  Super: "super",
+  Void: "void",
 });
 
+impl Keyword {
+  /// Contextual keywords only mean something in a few positions, and are
+  /// allowed as plain identifiers everywhere else.
+  pub(crate) fn is_contextual(self) -> bool {
+    matches!(self,
+      | Keyword::Template
+      | Keyword::Extends
+      | Keyword::Implements
+      | Keyword::Satisfies
+      | Keyword::Infer
+      | Keyword::From
+      | Keyword::In
+      | Keyword::Test
+    )
+  }
+
+  /// Keywords that start an `if`, a loop, a `switch` or `match`, or jump out
+  /// of one.
+  pub(crate) fn is_control_flow(self) -> bool {
+    matches!(self,
+      | Keyword::If
+      | Keyword::Unless
+      | Keyword::While
+      | Keyword::Until
+      | Keyword::Do
+      | Keyword::Loop
+      | Keyword::For
+      | Keyword::Switch
+      | Keyword::Match
+      | Keyword::Break
+      | Keyword::Continue
+      | Keyword::Return
+    )
+  }
+
+  /// Keywords that can only start a declaration, where the parser can pick
+  /// up again after an error.
+  pub(crate) fn starts_declaration(self) -> bool {
+    matches!(self,
+      | Keyword::Template
+      | Keyword::Type
+      | Keyword::Struct
+      | Keyword::Interface
+      | Keyword::Class
+      | Keyword::Namespace
+      | Keyword::Impl
+      | Keyword::Import
+      | Keyword::Export
+      | Keyword::Extern
+    )
+  }
+}
+
 enum_map!(Punctuation {
   Colon: ":",
   Semicolon: ";",
   Comma: ",",
+  DoubleDot: "..",
   Ellipsis: "...",
   RightArrow: "->",
   DoubleColon: "::",
   Bollocks: ":=",
+  FatArrow: "=>",
 });
 
 enum_map!(Grouping {
@@ -128,3 +196,18 @@ enum_map!(Grouping {
   CloseBracket: "]",
   CloseBrace: "}",
 });
+
+enum_map!(NumericSuffix {
+  U8: "u8",
+  U16: "u16",
+  U32: "u32",
+  U64: "u64",
+  Usize: "usize",
+  I8: "i8",
+  I16: "i16",
+  I32: "i32",
+  I64: "i64",
+  Isize: "isize",
+  F32: "f32",
+  F64: "f64",
+});
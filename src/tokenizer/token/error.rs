@@ -1,26 +1,98 @@
 use snafu::prelude::*;
 
-#[derive(Debug)]
+use crate::tokenizer::Span;
+
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum What {
   Identifier,
   Numeric,
   Float,
   Integer,
   Operator,
+  String,
+  ByteString,
+  CString,
+  Char,
+  ByteChar,
+  BlockComment,
+  InlineLlvm,
+  Escape,
+  HexEscape,
+  UnicodeEscape,
+  Declaration,
+  Parameter,
+  Receiver,
+  FunctionBody,
+  Type,
+  Expression,
+  MacroName,
+  Whitespace,
+  Comment,
+  /// Text that could not be tokenized.
+  InvalidToken,
+  EndOfFile,
+  /// A specific keyword, operator, punctuation or grouping token, given by
+  /// its text.
+  Exactly(&'static str),
 }
 
 #[derive(Debug, Snafu)]
 #[snafu(visibility(pub(crate)))]
 pub(crate) enum TokenError {
   #[snafu(display("expected {}", what.as_definite()))]
-  Expected { what: What },
+  Expected { what: What, span: Span },
 
   #[snafu(display("invalid {} {content:?}", what.as_name()))]
-  Invalid { what: What, content: String }
+  Invalid { what: What, content: String, span: Span },
+
+  #[snafu(display("unknown character {ch:?}"))]
+  UnknownCharacter { ch: char, span: Span },
+
+  #[snafu(display("unexpected {}", what.as_name()))]
+  Unexpected { what: What, span: Span },
+
+  #[snafu(display("unterminated {}", what.as_name()))]
+  Unterminated { what: What, span: Span },
+
+  #[snafu(display("unknown character escape {ch:?}"))]
+  UnknownEscape { ch: char, span: Span },
+
+  #[snafu(display("malformed {}", what.as_name()))]
+  MalformedEscape { what: What, span: Span },
+
+  #[snafu(display("{} out of range", what.as_name()))]
+  OutOfRange { what: What, span: Span },
+
+  #[snafu(display("empty {}", what.as_name()))]
+  Empty { what: What, span: Span },
+
+  #[snafu(display("{} must contain exactly one character", what.as_name()))]
+  MultipleCharacters { what: What, span: Span },
+
+  #[snafu(display("invalid suffix {suffix:?} for numeric literal"))]
+  InvalidSuffix { suffix: String, span: Span },
+
+  #[snafu(display("integer literal out of range for `{suffix}`"))]
+  IntegerOutOfRange { suffix: &'static str, span: Span },
+
+  #[snafu(display("numeric literal cannot start or end with a `_` separator"))]
+  MisplacedUnderscore { span: Span },
+
+  #[snafu(display("float literals must be written in decimal"))]
+  NonDecimalFloat { span: Span },
+
+  #[snafu(display("non-ASCII character {ch:?} in {}", what.as_name()))]
+  NonAscii { what: What, ch: char, span: Span },
+
+  #[snafu(display("identifier {name:?} mixes characters from different scripts"))]
+  MixedScript { name: String, span: Span },
+
+  #[snafu(display("identifier {name:?} can be confused with {other:?}"))]
+  Confusable { name: String, other: String, span: Span },
 }
 
 impl What {
-  fn as_strs(&self) -> (&str, &str) {
+  fn as_strs(&self) -> (&'static str, &'static str) {
     const A: &str = "a";
     const AN: &str = "an";
 
@@ -30,18 +102,73 @@ impl What {
       What::Operator => (AN, "operator"),
       What::Float => (A, "float"),
       What::Integer => (AN, "integer"),
+      What::String => (A, "string literal"),
+      What::ByteString => (A, "byte string literal"),
+      What::CString => (A, "C string literal"),
+      What::Char => (A, "character literal"),
+      What::ByteChar => (A, "byte character literal"),
+      What::BlockComment => (A, "block comment"),
+      What::InlineLlvm => (AN, "inline LLVM block"),
+      What::Escape => (AN, "escape sequence"),
+      What::HexEscape => (A, "hexadecimal escape"),
+      What::UnicodeEscape => (A, "unicode escape"),
+      What::Declaration => (A, "declaration"),
+      What::Parameter => (A, "parameter"),
+      What::Receiver => (A, "receiver"),
+      What::FunctionBody => (A, "function body"),
+      What::Type => (A, "type"),
+      What::Expression => (AN, "expression"),
+      What::MacroName => (A, "macro name"),
+      What::Whitespace => ("", "whitespace"),
+      What::Comment => (A, "comment"),
+      What::InvalidToken => (AN, "invalid token"),
+      What::EndOfFile => ("the", "end of the file"),
+      What::Exactly(text) => ("", text),
     }
   }
 
-  fn as_definite(&self) -> String {
-    let (a_or_an, name) = self.as_strs();
+  pub(crate) fn as_definite(&self) -> String {
+    if let What::Exactly(text) = self {
+      return format!("`{text}`");
+    };
 
-    format!("{a_or_an} {name}")
+    match self.as_strs() {
+      ("", name) => name.to_owned(),
+      (a_or_an, name) => format!("{a_or_an} {name}"),
+    }
   }
 
-  fn as_name(&self) -> &str {
+  pub(crate) fn as_name(&self) -> String {
+    if let What::Exactly(text) = self {
+      return format!("`{text}`");
+    };
+
     let (_, name) = self.as_strs();
 
-    name
+    name.to_owned()
+  }
+}
+
+impl TokenError {
+  pub(crate) fn span(&self) -> Span {
+    match self {
+      | TokenError::Expected { span, .. }
+      | TokenError::Invalid { span, .. }
+      | TokenError::UnknownCharacter { span, .. }
+      | TokenError::Unexpected { span, .. }
+      | TokenError::Unterminated { span, .. }
+      | TokenError::UnknownEscape { span, .. }
+      | TokenError::MalformedEscape { span, .. }
+      | TokenError::OutOfRange { span, .. }
+      | TokenError::Empty { span, .. }
+      | TokenError::MultipleCharacters { span, .. }
+      | TokenError::InvalidSuffix { span, .. }
+      | TokenError::IntegerOutOfRange { span, .. }
+      | TokenError::MisplacedUnderscore { span }
+      | TokenError::NonDecimalFloat { span }
+      | TokenError::NonAscii { span, .. }
+      | TokenError::MixedScript { span, .. }
+      | TokenError::Confusable { span, .. } => *span,
+    }
   }
 }
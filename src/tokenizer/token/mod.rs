@@ -6,31 +6,61 @@ pub(crate) use consts::*;
 pub(crate) use span::*;
 
 use crate::Result;
+use crate::compiler::{Interner, Symbol};
 use error::*;
 
 use crate::tokenizer::impls::numeric::NumericState;
+use crate::tokenizer::impls::string::StringPrefix;
 
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum NumericKind {
-  Float(f64),
-  Integer(u64),
+  Float(f64, Option<NumericSuffix>),
+  Integer(u64, Option<NumericSuffix>),
   // ^ signs are not read-in at the tokenization stage, therefore they cannot
   // have a sign.  the sign gets processed at the AST stage and later gets
   // computed by the postprocessor.
 }
 
+#[allow(unused)]
+#[derive(Debug, Clone)]
+pub(crate) enum StringKind {
+  Plain(String),
+  Byte(Vec<u8>),
+  C(Vec<u8>),
+  // ^ C strings are not NUL-terminated here, the terminator is only added
+  // once the literal is lowered.
+}
+
 #[allow(unused)]
 #[derive(Debug)]
 pub(crate) enum TokenKind {
+  /// Whitespace and comments keep only their span, which is enough to get
+  /// their text back from the source.
   Whitespace,
-  Identifier(String),
+  Identifier(Symbol),
+  /// An identifier glued to a `!`, like `defer!`, which invokes a macro.  The
+  /// name doesn't include the `!`, but the span does.
+  MacroName(Symbol),
   Operator(Operator),
   Keyword(Keyword),
-  Comment(String),
+  Comment,
+  DocComment,
   Punctuation(Punctuation),
   Grouping(Grouping),
   Numeric(NumericKind),
+  String(StringKind),
+  Char(char),
+  ByteChar(u8),
+  /// The `$` in `x |> f($)`, which stands in for the left-hand side of the
+  /// pipe.
+  PipePlaceholder,
+  /// The raw body of an `__llvm { ... }` block, to be spliced into the
+  /// generated IR as-is.
+  InlineLlvm(String),
+  /// Text that could not be tokenized, the error for which has already been
+  /// reported.
+  Error,
 }
 
 #[allow(unused)]
@@ -40,8 +70,31 @@ pub(crate) struct Token {
   pub span: Span,
 }
 
+impl NumericSuffix {
+  pub fn is_float(&self) -> bool {
+    matches!(self, NumericSuffix::F32 | NumericSuffix::F64)
+  }
+
+  /// The largest integer that can be written with this suffix.  Literals
+  /// have no sign yet, so signed types go up to the size of their minimum,
+  /// and pointer-sized types are taken to be 64 bits wide.
+  pub fn max_integer(&self) -> Option<u64> {
+    Some(match self {
+      NumericSuffix::U8 => u8::MAX.into(),
+      NumericSuffix::U16 => u16::MAX.into(),
+      NumericSuffix::U32 => u32::MAX.into(),
+      NumericSuffix::U64 | NumericSuffix::Usize => u64::MAX,
+      NumericSuffix::I8 => i8::MIN.unsigned_abs().into(),
+      NumericSuffix::I16 => i16::MIN.unsigned_abs().into(),
+      NumericSuffix::I32 => i32::MIN.unsigned_abs().into(),
+      NumericSuffix::I64 | NumericSuffix::Isize => i64::MIN.unsigned_abs(),
+      NumericSuffix::F32 | NumericSuffix::F64 => return None,
+    })
+  }
+}
+
 impl NumericKind {
-  pub fn from_state_and_content(state: super::impls::numeric::NumericState, content: &str) -> Result<Self> {
+  pub fn from_state_and_content(state: NumericState, content: &str, suffix: Option<NumericSuffix>, span: Span) -> Result<Self> {
     let base = match state {
       NumericState::Binary => 2,
       NumericState::Octal => 8,
@@ -49,20 +102,90 @@ impl NumericKind {
       NumericState::Hexadecimal => 16,
     };
 
+    let content = content.replace('_', "");
+
+    let is_float = suffix.as_ref().is_some_and(NumericSuffix::is_float)
+      || state == NumericState::Decimal && content.contains(['.', 'e']);
+
     Ok({
-      if content.find('.').is_some() {
+      if is_float {
         let Ok(value) = content.parse() else {
-          return InvalidSnafu { what: What::Float, content }.fail()?;
+          return InvalidSnafu { what: What::Float, content, span }.fail()?;
         };
 
-        Self::Float(value)
+        Self::Float(value, suffix)
       } else {
-        let Ok(value) = u64::from_str_radix(content, base) else {
-          return InvalidSnafu { what: What::Integer, content }.fail()?;
+        let Ok(value) = u64::from_str_radix(&content, base) else {
+          return InvalidSnafu { what: What::Integer, content, span }.fail()?;
         };
 
-        Self::Integer(value)
+        if let Some(suffix) = suffix {
+          if suffix.max_integer().is_some_and(|max| value > max) {
+            return IntegerOutOfRangeSnafu { suffix: suffix.to_str(), span }.fail()?;
+          };
+        };
+
+        Self::Integer(value, suffix)
       }
     })
   }
 }
+
+impl StringKind {
+  pub fn from_prefix_and_content(prefix: StringPrefix, content: Vec<u8>) -> Self {
+    match prefix {
+      StringPrefix::Plain => Self::Plain(
+        String::from_utf8(content).expect("plain strings are only built from whole characters")
+      ),
+      StringPrefix::Byte => Self::Byte(content),
+      StringPrefix::C => Self::C(content),
+    }
+  }
+}
+
+#[allow(unused)]
+impl TokenKind {
+  /// Returns the name of an identifier, or of a contextual keyword that is
+  /// being used as one.
+  pub(crate) fn as_identifier(&self, interner: &mut Interner) -> Option<Symbol> {
+    match self {
+      TokenKind::Identifier(name) => Some(*name),
+      TokenKind::Keyword(keyword) if keyword.is_contextual() => Some(interner.intern(keyword.to_str())),
+      _ => None,
+    }
+  }
+
+  /// What the token is, for errors about finding it where something else was
+  /// expected.
+  pub(crate) fn what(&self) -> What {
+    match self {
+      TokenKind::Whitespace => What::Whitespace,
+      TokenKind::Identifier(_) => What::Identifier,
+      TokenKind::MacroName(_) => What::MacroName,
+      TokenKind::Operator(operator) => What::Exactly(operator.to_str()),
+      TokenKind::Keyword(keyword) => What::Exactly(keyword.to_str()),
+      TokenKind::Comment | TokenKind::DocComment => What::Comment,
+      TokenKind::Punctuation(punctuation) => What::Exactly(punctuation.to_str()),
+      TokenKind::Grouping(grouping) => What::Exactly(grouping.to_str()),
+      TokenKind::Numeric(NumericKind::Float(..)) => What::Float,
+      TokenKind::Numeric(NumericKind::Integer(..)) => What::Integer,
+      TokenKind::String(StringKind::Plain(_)) => What::String,
+      TokenKind::String(StringKind::Byte(_)) => What::ByteString,
+      TokenKind::String(StringKind::C(_)) => What::CString,
+      TokenKind::Char(_) => What::Char,
+      TokenKind::ByteChar(_) => What::ByteChar,
+      TokenKind::PipePlaceholder => What::Exactly("$"),
+      TokenKind::InlineLlvm(_) => What::InlineLlvm,
+      TokenKind::Error => What::InvalidToken,
+    }
+  }
+
+  /// Whitespace and comments, which carry no meaning for the parser.
+  pub(crate) fn is_trivia(&self) -> bool {
+    matches!(self,
+      | TokenKind::Whitespace
+      | TokenKind::Comment
+      | TokenKind::DocComment
+    )
+  }
+}
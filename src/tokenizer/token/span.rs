@@ -1,19 +1,44 @@
-#[allow(unused)]
-#[derive(Debug)]
+use crate::compiler::FileId;
+
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct Span {
-  start: usize,
-  end: usize,
+  pub(crate) file: FileId,
+  pub(crate) start: usize,
+  pub(crate) end: usize,
+}
+
+impl Span {
+  /// Covers everything from the start of this span to the end of `end`.
+  pub(crate) fn to(self, end: Span) -> Span {
+    Span {
+      file: self.file,
+      start: self.start,
+      end: end.end,
+    }
+  }
+
+  /// An empty span just past the end of this one.
+  pub(crate) fn after(self) -> Span {
+    Span {
+      file: self.file,
+      start: self.end,
+      end: self.end,
+    }
+  }
 }
 
 #[derive(Debug, Clone, Copy)]
-pub(in crate::tokenizer) struct SpanStart(pub usize);
+pub(in crate::tokenizer) struct SpanStart {
+  pub file: FileId,
+  pub position: usize,
+}
 
 impl SpanStart {
-  pub(in crate::tokenizer) fn into_span(&self, end: usize) -> Span {
+  pub(in crate::tokenizer) fn into_span(self, end: usize) -> Span {
     Span {
-      start: self.0,
+      file: self.file,
+      start: self.position,
       end,
     }
   }
 }
-
@@ -1,4 +1,5 @@
 use crate::tokenizer;
+use crate::asterizer;
 use crate::todo;
 
 #[derive(Clone, Copy)]
@@ -6,7 +7,7 @@ pub(crate) struct DefaultWorkflow;
 
 impl crate::compiler::CompilerWorkflow for DefaultWorkflow {
   type Tokenizer = tokenizer::Tokenizer;
-  type Asterizer = todo::Asterizer;
+  type Asterizer = asterizer::Asterizer;
   type Translator = todo::Translator;
   type Checker = todo::Checker;
   type Generator = todo::Generator;